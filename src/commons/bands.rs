@@ -0,0 +1,128 @@
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Represents the number of bands of rows per thread when stepping in parallel.
+/// More bands than threads balance the work of rows that are slower to step.
+#[cfg(feature = "parallel")]
+const BANDS_PER_THREAD: usize = 4;
+
+/// A function that calls the given function with the index and the slice of every row of the
+/// given buffer (of rows with the given length) and returns the results of the rows combined
+/// with the given function, starting from the default result.
+///
+/// With the ``parallel`` feature the rows are split into bands of consecutive rows that are
+/// stepped on the threads of the rayon thread pool. Each row is given to the function exactly
+/// once either way, so the rows of the buffer are the same as when stepped serially.
+pub fn step_rows<T, S, F, C>(buffer: &mut [T], length: usize, f: F, combine: C) -> S
+where
+    T: Send,
+    S: Default + Send,
+    F: Fn(usize, &mut [T]) -> S + Sync,
+    C: Fn(S, S) -> S + Sync,
+{
+    // Check if the buffer has any rows
+    if length == 0 {
+        return S::default();
+    }
+
+    #[cfg(feature = "parallel")]
+    {
+        // Calculate the number of rows in each band
+        let rows = buffer.len() / length;
+        let band = rows.div_ceil(rayon::current_num_threads() * BANDS_PER_THREAD).max(1);
+
+        // Step the bands in parallel, stepping the rows of each band in order
+        buffer.par_chunks_mut(band * length)
+            .enumerate()
+            .map(|(b, chunk)| chunk.chunks_mut(length)
+                .enumerate()
+                .fold(S::default(), |result, (i, row)| combine(result, f(b * band + i, row))))
+            .reduce(S::default, &combine)
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    {
+        // Step the rows in order
+        buffer.chunks_mut(length)
+            .enumerate()
+            .fold(S::default(), |result, (y, row)| combine(result, f(y, row)))
+    }
+}
+
+/// A function that sets the number of threads used to step grids in parallel.
+/// Returns false if the thread pool was already started or the ``parallel`` feature is disabled.
+pub fn setthreads(threads: usize) -> bool {
+    #[cfg(feature = "parallel")]
+    {
+        rayon::ThreadPoolBuilder::new().num_threads(threads).build_global().is_ok()
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    {
+        let _ = threads;
+        false
+    }
+}
+
+#[cfg(all(test, feature = "parallel"))]
+mod tests {
+    use crate::simulation::{Automaton, SimGrid};
+    use crate::commons::cells::BinaryCell;
+    use crate::commons::grids::{BitGrid, Boundary, CellGrid, Neighbourhood};
+    use crate::gameoflife::GameOfLife;
+
+    // Represents the number of generations that each grid is stepped
+    const GENERATIONS: usize = 30;
+
+    // A function that steps the given grid by a generation of the game of life one cell at a time
+    fn step_serial(grid: &CellGrid<BinaryCell>) -> CellGrid<BinaryCell> {
+        let moore = Neighbourhood::moore();
+        let mut next = CellGrid::from_fn(grid.getwidth(), grid.getheight(), |x, y| {
+            let alive = grid.neighbours(x, y, &moore).filter(|(_, _, &cell)| cell == BinaryCell::Active).count();
+            match (grid[(x, y)], alive) {
+                (BinaryCell::Active, 2) | (_, 3) => BinaryCell::Active,
+                _ => BinaryCell::Passive,
+            }
+        });
+        next.boundary = grid.boundary;
+        next
+    }
+
+    #[test]
+    fn parallel_steps_match_serial_steps() {
+        // Step on more threads than cores, so that the rows are split into many bands
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(7).build().unwrap();
+
+        // The heights are not multiples of the number of bands (7 threads with 4 bands each)
+        for &(width, height) in &[(70, 53), (130, 29), (200, 3)] {
+            for &boundary in &[Boundary::Dead, Boundary::Toroidal] {
+                // Create the automata with the same seeded random grid
+                let mut cellgrid = GameOfLife::<CellGrid<BinaryCell>>::new("random-balanced", 42);
+                let mut bitgrid = GameOfLife::<BitGrid>::new("random-balanced", 42);
+                cellgrid.initialize(width, height).unwrap();
+                bitgrid.initialize(width, height).unwrap();
+                cellgrid.setboundary(boundary).unwrap();
+                bitgrid.setboundary(boundary).unwrap();
+
+                // Step the reference grid serially
+                let mut expected = cellgrid.grid().clone();
+                for _ in 0..GENERATIONS {
+                    expected = step_serial(&expected);
+                }
+
+                // Step the automata in parallel
+                pool.install(|| {
+                    for _ in 0..GENERATIONS {
+                        cellgrid.advance();
+                        bitgrid.advance();
+                    }
+                });
+
+                // Check that the grids are equal (and still alive, so that the check is not trivial)
+                assert!(cellgrid.alive() > 0, "grid {}x{} {} died out", width, height, boundary);
+                assert!(cellgrid.grid().cells() == expected.cells(), "grid {}x{} {}", width, height, boundary);
+                assert!(expected.iter().all(|(x, y, &cell)| bitgrid.grid().getcell(x as isize, y as isize) == Some(cell)), "packed {}x{} {}", width, height, boundary);
+            }
+        }
+    }
+}
@@ -1,4 +1,6 @@
 use rand::Rng;
+use serde::{Serialize, Deserialize};
+use crate::{Error, Result};
 use crate::simulation::SimCell;
 
 /// An enum that represents the states of a binary cell.
@@ -6,7 +8,7 @@ use crate::simulation::SimCell;
 /// Possibile states:
 /// - ``BinaryCell::Active`` <- represents the ON state
 /// - ``BinaryCell::Passive`` <- represents the OFF state
-#[derive(Debug, PartialEq, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
 pub enum BinaryCell {
     /// Represent the off state
     Passive = 0,
@@ -18,9 +20,9 @@ pub enum BinaryCell {
 impl SimCell for BinaryCell {
     /// A constructor function that generates a new BinaryCell with a balanced random state.
     /// Balanced generation means that there is an equal probability for a cell to be passive or active.
-    fn balanced() -> Self {
+    fn balanced<R: Rng + ?Sized>(rng: &mut R) -> Self {
         // Randomly generate a number between 0 and 1.
-        let val = rand::thread_rng().gen_range(0..=1);
+        let val = rng.gen_range(0..=1);
 
         // Check the value of generated number.
         match val {
@@ -34,19 +36,20 @@ impl SimCell for BinaryCell {
     /// A constructor function that generates a new BinaryCell with a skewed random state.
     /// Skewed generation means that there is a higher probability for a cell to be a particular state.
     ///
+    /// - @param *rng* is the random number generator used for the state.
     /// - @param *skew* is a bool that determines whether the skew state is passive or active.
     /// - @param *bias* is an i8 that represents the skew ratio between the non-preferred and preferred state. 
     ///
     /// A bias of 1 is equivalent to a balanced state generation regardless of the skew value (1:1).
     /// A bias of 100 with a skew value of "active", results in 1:100 ratio leaning towards the active state.
     ///
-    /// Valid values for skew are "active" and "passive".
-    fn skewed(skew: &str, bias: i8) -> Self {
+    /// Valid values for skew are "active" and "passive". Returns an error for any other value.
+    fn skewed<R: Rng + ?Sized>(rng: &mut R, skew: &str, bias: i8) -> Result<Self> {
         // Randomly generate a number between 0 and the given bias
-        let val = rand::thread_rng().gen_range(0..=bias);
+        let val = rng.gen_range(0..=bias);
 
         // Check the skew value
-        match skew {
+        let cell = match skew {
             // Skew towards the active state
             "active" => match val {
                 // If generated number is 0, return a Passive cell.
@@ -62,20 +65,17 @@ impl SimCell for BinaryCell {
                 _ => BinaryCell::Passive,
             }
             // Invalid skew value
-            _ => panic!("invalid skew value")
-        }
+            _ => return Err(Error::InvalidSkew(skew.to_string())),
+        };
+
+        Ok(cell)
     }
-}
 
-/// Implementation of the Clone trait for BinaryCell
-impl Clone for BinaryCell {
-    /// A method that returns a new BinaryCell with the same state as the current BinaryCell.
-    fn clone(&self) -> Self {
+    /// A method that returns the flipped state of the cell.
+    fn cycle(&self) -> Self {
         match self {
-            // Cloning a passive cell
-            BinaryCell::Passive => BinaryCell::Passive,
-            // Cloning an active cell
-            BinaryCell::Active => BinaryCell::Active,
+            BinaryCell::Passive => BinaryCell::Active,
+            BinaryCell::Active => BinaryCell::Passive,
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,493 @@
+use std::convert::TryFrom;
+
+use rand::Rng;
+use serde::{Serialize, Deserialize};
+
+use crate::{Error, Result};
+use crate::commons::bands;
+use crate::commons::cells::BinaryCell;
+use crate::commons::navigation::Direction4;
+use crate::simulation::SimGrid;
+use crate::commons::grids::{self, Boundary, CellGrid, GridCell, GridPoint, GridScanner};
+
+/// Represents the number of cells packed into a word of the grid.
+pub const WORD_BITS: usize = 64;
+
+/// A struct that represents a grid of binary cells packed 64 cells to a word.
+///
+/// Each row of the grid is a run of ``stride`` u64 words in a single row-major vector.
+/// The cell at (x, y) is bit ``x % 64`` of word ``y * stride + x / 64`` and is set if the
+/// cell is active. The bits beyond the width of the grid in the last word of a row are always 0.
+///
+/// The grid is double-buffered like CellGrid.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(try_from = "BitGridState")]
+pub struct BitGrid {
+    /// Represents the number of columns of the grid
+    width: usize,
+    /// Represents the number of rows of the grid
+    height: usize,
+    /// Represents the words of the grid in row-major order (the front buffer)
+    words: Vec<u64>,
+    /// Represents the words of the next step of the grid (the back buffer)
+    #[serde(skip)]
+    back: Vec<u64>,
+    /// Represents the policy for positions beyond the edges of the grid
+    #[serde(default)]
+    pub boundary: Boundary,
+}
+
+/// A struct that represents the serialized state of a BitGrid.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BitGridState {
+    /// Represents the number of columns of the grid
+    width: usize,
+    /// Represents the number of rows of the grid
+    height: usize,
+    /// Represents the words of the grid in row-major order
+    words: Vec<u64>,
+    /// Represents the policy for positions beyond the edges of the grid
+    #[serde(default)]
+    boundary: Boundary,
+}
+
+/// Implementation of the SimGrid trait for BitGrid
+impl SimGrid for BitGrid {
+    /// Define the type of cell in the grid
+    type Cell = BinaryCell;
+
+    /// Define the type of compass
+    type Orientation = Direction4;
+
+    /// A constructor method that creates a null grid.
+    fn new() -> Self {
+        Self {
+            width: 0,
+            height: 0,
+            words: Vec::new(),
+            back: Vec::new(),
+            boundary: Boundary::default(),
+        }
+    }
+
+    /// A getter method that returns the name of the type of grid.
+    /// Ex: "Packed Grid"
+    fn name(&self) -> String {
+        "Packed Grid".to_string()
+    }
+
+    /// A setter method that packs the cells of the given grid into the struct.
+    /// The boundary policy of the struct is kept.
+    fn setgrid(&mut self, other: CellGrid<Self::Cell>) {
+        self.width = other.getwidth();
+        self.height = other.getheight();
+        self.words = vec![0; self.stride() * self.height];
+
+        // Set the bits of the active cells
+        for (x, y, &cell) in other.iter() {
+            if cell == BinaryCell::Active {
+                self.setcell(x as isize, y as isize, cell);
+            }
+        }
+    }
+
+    /// A setter method that sets the cell at the given position.
+    /// Returns false if the grid is null or the position is out of bounds.
+    fn setcell(&mut self, x: isize, y: isize, cell: Self::Cell) -> bool {
+        match grids::unsigned(x, y).and_then(|(x, y)| self.offset(x, y)) {
+            None => false,
+            Some((index, bit)) => {
+                match cell {
+                    BinaryCell::Active => self.words[index] |= bit,
+                    BinaryCell::Passive => self.words[index] &= !bit,
+                }
+                true
+            }
+        }
+    }
+
+    /// A getter method that returns the height of the grid (number of rows)
+    /// Returns 0 if the grid is null.
+    fn getheight(&self) -> usize {
+        self.height
+    }
+
+    /// A getter method that returns the width of the grid (number of columns).
+    /// Returns 0 if the grid is null.
+    fn getwidth(&self) -> usize {
+        self.width
+    }
+
+    /// A getter method that returns the cell at the given position.
+    /// Returns None if the grid is null or the position is out of bounds.
+    fn getcell(&self, x: isize, y: isize) -> Option<Self::Cell> {
+        grids::unsigned(x, y).and_then(|(x, y)| self.offset(x, y)).map(|(index, bit)| cell(self.words[index] & bit))
+    }
+
+    /// A getter method that returns the boundary policy of the grid.
+    fn boundary(&self) -> Boundary {
+        self.boundary
+    }
+
+    /// A setter method that sets the boundary policy of the grid.
+    fn setboundary(&mut self, boundary: Boundary) {
+        self.boundary = boundary;
+    }
+
+    /// A method that returns a random cell from the grid.
+    /// Returns the x,y position of the cell along with cell state as a GridPoint
+    /// or an error if the grid is null or has no cells.
+    fn randomcell<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<GridPoint<Self::Cell>> {
+        // Check if the grid has any cells
+        if self.width == 0 || self.height == 0 {
+            return Err(Error::EmptyGrid);
+        }
+
+        // Get a random column and row from the grid
+        let col = rng.gen_range(0..self.width);
+        let row = rng.gen_range(0..self.height);
+        // Build the GridPoint and return it
+        let (col, row) = (col as isize, row as isize);
+        self.getcell(col, row).map(|cell| (col, row, cell)).ok_or(Error::EmptyGrid)
+    }
+
+    /// A method that returns the state in the given previous grid
+    /// of every cell that differs between it and the current grid.
+    fn changes(&self, previous: &Self) -> Vec<GridPoint<Self::Cell>> {
+        // Check if the grids have the same dimensions
+        if (self.width, self.height) != (previous.width, previous.height) {
+            // Look up every cell of the previous grid
+            return previous.iter()
+                .map(|(x, y, cell)| (x as isize, y as isize, cell))
+                .filter(|&(x, y, cell)| self.getcell(x, y) != Some(cell))
+                .collect();
+        }
+
+        // Compare the grids a word at a time and collect the bits that differ
+        let stride = self.stride();
+        let mut changes = Vec::new();
+        for (index, (&new, &old)) in self.words.iter().zip(previous.words.iter()).enumerate() {
+            let (y, x) = (index / stride, (index % stride) * WORD_BITS);
+            for bit in bits(new ^ old) {
+                changes.push(((x + bit) as isize, y as isize, cell(old & (1 << bit))));
+            }
+        }
+
+        changes
+    }
+}
+
+/// Implementation of helper methods for BitGrid.
+impl BitGrid {
+    /// A getter method that returns the number of words in each row of the grid.
+    pub fn stride(&self) -> usize {
+        self.width.div_ceil(WORD_BITS)
+    }
+
+    /// A method that returns the index of the word and the bit mask of the cell at
+    /// the given position. Returns None if the position is out of bounds.
+    pub fn offset(&self, x: usize, y: usize) -> Option<(usize, u64)> {
+        match x < self.width && y < self.height {
+            true => Some((y * self.stride() + x / WORD_BITS, 1 << (x % WORD_BITS))),
+            false => None,
+        }
+    }
+
+    /// A getter method that returns the words of the given row of the grid.
+    /// Panics if the row is out of bounds.
+    pub fn row(&self, y: usize) -> &[u64] {
+        let stride = self.stride();
+        &self.words[y * stride..(y + 1) * stride]
+    }
+
+    /// A method that returns the words of the row at the given position (which may lie
+    /// beyond the edges of the grid) according to the boundary policy of the grid.
+    /// Returns None if the row lies beyond a dead edge.
+    pub fn neighbour(&self, y: isize) -> Option<&[u64]> {
+        self.boundary.resolve(0, y, self.width, self.height).map(|(_, y)| self.row(y))
+    }
+
+    /// A method that returns the mask of the bits of the given word of a row
+    /// that hold cells, i.e. all bits except those beyond the width of the grid.
+    pub fn mask(&self, k: usize) -> u64 {
+        match (k + 1) * WORD_BITS > self.width {
+            true => u64::MAX >> ((k + 1) * WORD_BITS - self.width),
+            false => u64::MAX,
+        }
+    }
+
+    /// A method that returns the given word of the given row shifted so that each bit holds the
+    /// cell to its west (x - 1). The cell west of the first column follows the boundary policy.
+    pub fn west(&self, row: &[u64], k: usize) -> u64 {
+        // Carry the last cell of the previous word (or the cell beyond the west edge)
+        let carry = match k {
+            0 => self.edge(row, -1),
+            _ => row[k - 1] >> (WORD_BITS - 1),
+        };
+
+        (row[k] << 1) | carry
+    }
+
+    /// A method that returns the given word of the given row shifted so that each bit holds the
+    /// cell to its east (x + 1). The cell east of the last column follows the boundary policy.
+    pub fn east(&self, row: &[u64], k: usize) -> u64 {
+        // Carry the first cell of the next word (or the cell beyond the east edge into the last column)
+        let carry = match k + 1 == row.len() {
+            true => self.edge(row, self.width as isize) << ((self.width - 1) % WORD_BITS),
+            false => row[k + 1] << (WORD_BITS - 1),
+        };
+
+        (row[k] >> 1) | carry
+    }
+
+    /// A method that resizes the grid to the given dimensions (in cells). The cells that remain
+    /// within the grid keep their positions (anchored to the top left corner) and the new cells
+    /// are passive.
+    pub fn resize(&mut self, width: usize, height: usize) {
+        // Take the words and stride of the grid before it is resized
+        let previous = std::mem::take(&mut self.words);
+        let oldstride = self.stride();
+
+        // Set the dimensions of the grid and allocate its words
+        self.width = width;
+        self.height = height;
+        self.back = Vec::new();
+        let stride = self.stride();
+        self.words = vec![0; stride * height];
+
+        // Check if any words of the previous grid are kept
+        if stride == 0 || oldstride == 0 {
+            return;
+        }
+
+        // Copy the kept words of the rows that remain within the grid and
+        // clear the bits of the last word of each row beyond the new width
+        let (kept, mask) = (stride.min(oldstride), self.mask(stride - 1));
+        for (row, old) in self.words.chunks_mut(stride).zip(previous.chunks(oldstride)) {
+            row[..kept].copy_from_slice(&old[..kept]);
+            row[stride - 1] &= mask;
+        }
+    }
+
+    /// A method that steps the grid like ``CellGrid::step``, with rows of words.
+    /// The bits beyond the width of the grid are cleared before the buffers are swapped.
+    pub fn step<S, F, C>(&mut self, f: F, combine: C) -> S
+    where
+        S: Default + Send,
+        F: Fn(&Self, usize, &mut [u64]) -> S + Sync,
+        C: Fn(S, S) -> S + Sync,
+    {
+        // Take the back buffer and size it to the front buffer
+        let mut back = std::mem::take(&mut self.back);
+        back.resize(self.words.len(), 0);
+
+        // Compute the next state of every row into the back buffer
+        let (grid, stride) = (&*self, self.stride());
+        let result = bands::step_rows(&mut back, stride, |y, row| {
+            let result = f(grid, y, row);
+            row[stride - 1] &= grid.mask(stride - 1);
+            result
+        }, combine);
+
+        // Swap the buffers and keep the previous words as the next back buffer
+        self.back = std::mem::replace(&mut self.words, back);
+        result
+    }
+
+    /// A getter method that returns the words of the grid in row-major order.
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// A method that returns an iterator over the positions and cells
+    /// of the grid as ``(x, y, cell)`` in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = GridCell<BinaryCell>> + '_ {
+        (0..self.height).flat_map(move |y| {
+            let row = self.row(y);
+            (0..self.width).map(move |x| (x, y, cell(row[x / WORD_BITS] & (1 << (x % WORD_BITS)))))
+        })
+    }
+
+    /// A method that returns an iterator over the positions of the active cells of the grid
+    /// in row-major order. Words without any active cells are skipped at once.
+    pub fn live(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let stride = self.stride();
+        self.words.iter().enumerate().flat_map(move |(index, &word)| {
+            let (y, x) = (index / stride, (index % stride) * WORD_BITS);
+            bits(word).map(move |bit| (x + bit, y))
+        })
+    }
+
+    /// A method that counts the cells of the grid by state.
+    /// Returns the number of active and passive cells as a tuple.
+    pub fn population(&self) -> (u32, u32) {
+        let active: u32 = self.words.iter().map(|word| word.count_ones()).sum();
+        (active, (self.width * self.height) as u32 - active)
+    }
+
+    // A method that returns the cell of the given row at the given column beyond the edge
+    // of the grid (as the lowest bit of a word) according to the boundary policy of the grid.
+    fn edge(&self, row: &[u64], x: isize) -> u64 {
+        match self.boundary.resolve(x, 0, self.width, 1) {
+            Some((x, _)) => (row[x / WORD_BITS] >> (x % WORD_BITS)) & 1,
+            None => 0,
+        }
+    }
+}
+
+/// Implementation of the Clone trait for BitGrid
+impl Clone for BitGrid {
+    /// A method that returns a new BitGrid with the same words and properties (without the back buffer).
+    fn clone(&self) -> Self {
+        Self {
+            width: self.width,
+            height: self.height,
+            words: self.words.clone(),
+            back: Vec::new(),
+            boundary: self.boundary,
+        }
+    }
+
+    /// A method that copies the words and properties of the given BitGrid
+    /// into the current BitGrid, reusing the allocation of its words.
+    fn clone_from(&mut self, source: &Self) {
+        self.width = source.width;
+        self.height = source.height;
+        self.words.clone_from(&source.words);
+        self.boundary = source.boundary;
+    }
+}
+
+/// Implementation of the conversion of a serialized state into a BitGrid.
+/// The state must hold a run of words for every row and no bits beyond the width of the grid.
+impl TryFrom<BitGridState> for BitGrid {
+    /// Define the error type of the conversion
+    type Error = Error;
+
+    fn try_from(state: BitGridState) -> Result<Self> {
+        let grid = Self { width: state.width, height: state.height, words: state.words, back: Vec::new(), boundary: state.boundary };
+
+        // Check that the number of words matches the dimensions of the grid
+        let stride = grid.stride();
+        let expected = stride.checked_mul(grid.height);
+        if expected != Some(grid.words.len()) {
+            return Err(Error::SnapshotMismatch {
+                expected: format!("{}x{} {} ({} words)", grid.width, grid.height, grid.name(), expected.unwrap_or(usize::MAX)),
+                found: format!("{} words", grid.words.len()),
+            });
+        }
+
+        // Check that the bits beyond the width of the grid are clear
+        let mask = grid.mask(stride.saturating_sub(1));
+        if stride > 0 && grid.words.chunks(stride).any(|row| row[stride - 1] & !mask != 0) {
+            return Err(Error::SnapshotMismatch {
+                expected: format!("{}x{} {}", grid.width, grid.height, grid.name()),
+                found: "cells beyond the width of the grid".to_string(),
+            });
+        }
+
+        Ok(grid)
+    }
+}
+
+/// Implementation of the IntoIterator trait for BitGrid
+impl IntoIterator for BitGrid {
+    /// Define the iteration item type
+    type Item = GridCell<BinaryCell>;
+    /// Define the iterator type
+    type IntoIter = GridScanner<std::vec::IntoIter<BinaryCell>>;
+
+    /// A method that creates an iterator that consumes the BitGrid.
+    /// A null grid creates an iterator without any cells.
+    fn into_iter(self) -> Self::IntoIter {
+        let cells: Vec<BinaryCell> = self.iter().map(|(_, _, cell)| cell).collect();
+        GridScanner::new(cells.into_iter(), self.width)
+    }
+}
+
+// A function that returns the cell state of the given masked bit.
+fn cell(bit: u64) -> BinaryCell {
+    match bit {
+        0 => BinaryCell::Passive,
+        _ => BinaryCell::Active,
+    }
+}
+
+// A function that returns an iterator over the indices of the set bits of the given word.
+fn bits(mut word: u64) -> impl Iterator<Item = usize> {
+    std::iter::from_fn(move || match word {
+        0 => None,
+        _ => {
+            let bit = word.trailing_zeros() as usize;
+            word &= word - 1;
+            Some(bit)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use crate::Error;
+    use crate::simulation::SimGrid;
+    use crate::commons::cells::BinaryCell;
+    use crate::commons::grids::BitGrid;
+    use super::BitGridState;
+
+    #[test]
+    fn snapshot_round_trip() {
+        // Create a grid with a cell in the last column of its second word
+        let mut grid = BitGrid::new();
+        grid.resize(70, 3);
+        grid.setcell(69, 2, BinaryCell::Active);
+
+        // Check that the grid is restored from its serialized state
+        let json = serde_json::to_string(&grid).unwrap();
+        let restored: BitGrid = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.words(), grid.words());
+        assert_eq!(restored.getcell(69, 2), Some(BinaryCell::Active));
+    }
+
+    #[test]
+    fn resize_keeps_top_left() {
+        // Create a grid with a diagonal of cells that crosses the edge of the first words
+        let mut grid = BitGrid::new();
+        grid.resize(70, 70);
+        for i in 0..70 {
+            grid.setcell(i, i, BinaryCell::Active);
+        }
+
+        // Shrink the grid within the first words and grow it back beyond them
+        grid.resize(63, 66);
+        grid.resize(130, 68);
+        assert_eq!(grid.words().len(), 68 * 3);
+        for (x, y, cell) in grid.iter() {
+            let expected = match x == y && x < 63 {
+                true => BinaryCell::Active,
+                false => BinaryCell::Passive,
+            };
+            assert_eq!(cell, expected, "cell ({}, {})", x, y);
+        }
+        assert_eq!(grid.population(), (63, 130 * 68 - 63));
+    }
+
+    #[test]
+    fn snapshot_word_mismatch() {
+        // A 70x3 grid has 2 words to a row, so it holds 6 words
+        for json in [
+            r#"{"width":70,"height":3,"words":[0,0,0,0,0]}"#,
+            r#"{"width":70,"height":3,"words":[0,0,0,0,0,0,0]}"#,
+            r#"{"width":70,"height":3,"words":[0,64,0,0,0,0]}"#,
+            r#"{"width":18446744073709551615,"height":3,"words":[]}"#,
+        ] {
+            let error = serde_json::from_str::<BitGrid>(json).unwrap_err();
+            assert!(error.to_string().starts_with("snapshot is of"), "{}: {}", json, error);
+        }
+
+        // Check that the error of the conversion is a snapshot mismatch
+        let state: BitGridState = serde_json::from_str(r#"{"width":70,"height":3,"words":[]}"#).unwrap();
+        assert!(matches!(BitGrid::try_from(state), Err(Error::SnapshotMismatch { .. })));
+    }
+}
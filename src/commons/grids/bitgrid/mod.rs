@@ -0,0 +1,6 @@
+pub mod core;
+pub mod pattern;
+#[cfg(feature = "graphics")]
+pub mod render;
+
+pub use crate::commons::grids::bitgrid::core::BitGrid;
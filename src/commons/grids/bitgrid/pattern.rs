@@ -0,0 +1,42 @@
+use crate::commons::cells::BinaryCell;
+use crate::simulation::SimGrid;
+use crate::commons::grids::{BitGrid, GridPoint, Pattern, Placement};
+
+/// Implementation of methods for Pattern on a BitGrid.
+impl Pattern {
+    /// A constructor function that creates a pattern from the live cells of the given bit grid.
+    /// If bounded is set, the pattern is cropped to the bounding box of the live cells,
+    /// otherwise the pattern spans the full grid.
+    pub fn from_bitgrid(grid: &BitGrid, bounded: bool) -> Self {
+        // Collect the positions of the live cells of the grid (in row order)
+        let cells: Vec<(usize, usize)> = grid.live().collect();
+
+        // Check if the pattern spans the full grid
+        if !bounded {
+            return Self { width: grid.getwidth(), height: grid.getheight(), cells, ..Self::default() };
+        }
+
+        // Crop the cells to their bounding box
+        let left = cells.iter().map(|&(x, _)| x).min().unwrap_or(0);
+        let top = cells.iter().map(|&(_, y)| y).min().unwrap_or(0);
+        Self::new(cells.into_iter().map(|(x, y)| (x - left, y - top)).collect())
+    }
+}
+
+/// Implementation of pattern placement methods for BitGrid.
+impl BitGrid {
+    /// A method that returns the cells that place the given pattern onto the grid.
+    /// Every cell of the bounding box of the pattern is included (live and dead),
+    /// and cells that fall outside the grid are clipped.
+    pub fn placement(&self, pattern: &Pattern, placement: Placement) -> Vec<GridPoint<BinaryCell>> {
+        pattern.clipped(placement, self.getwidth(), self.getheight())
+    }
+
+    /// A method that places the given pattern onto the grid.
+    /// Cells of the pattern that fall outside the grid are clipped.
+    pub fn place(&mut self, pattern: &Pattern, placement: Placement) {
+        for (x, y, cell) in self.placement(pattern, placement) {
+            self.setcell(x, y, cell);
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use ggez::graphics;
+
+use crate::simulation::SimGrid;
+use crate::commons::grids::BitGrid;
+use crate::commons::grids::bitgrid::core::WORD_BITS;
+use crate::commons::grids::cellgrid::render::visible_range;
+
+/// Implementation of rendering methods for BitGrid.
+impl BitGrid {
+    /// A method that adds the cells of the grid within the visible region (in grid space)
+    /// to the given mesh builder. The grid is filled black with its live cells filled white
+    /// on top and the grid is outlined with a faint boundary. Cells are not outlined one by
+    /// one, so that large grids at small cell sizes stay cheap to draw.
+    pub fn render(&self, mb: &mut graphics::MeshBuilder, cellsize: f32, visible: graphics::Rect) {
+        // Create the bounds of the grid
+        let bounds = graphics::Rect::new(0.0, 0.0, self.getwidth() as f32 * cellsize, self.getheight() as f32 * cellsize);
+
+        // Add the dead background and the outline of the grid to the mesh builder
+        mb.rectangle(
+            graphics::DrawMode::Fill(graphics::FillOptions::default()),
+            bounds,
+            [0.0, 0.0, 0.0, 1.0].into(),
+        )
+        .rectangle(
+            graphics::DrawMode::Stroke(graphics::StrokeOptions::default()),
+            bounds,
+            [1.0, 1.0, 1.0, 0.25].into(),
+        );
+
+        // Calculate the range of columns and rows within the visible region
+        let (xstart, xend) = visible_range(visible.x, visible.w, cellsize, self.getwidth());
+        let (ystart, yend) = visible_range(visible.y, visible.h, cellsize, self.getheight());
+        // Check if any cell is visible
+        if xstart >= xend {
+            return;
+        }
+
+        // Iterate through the words of each visible row that hold visible cells
+        for y in ystart..yend {
+            let row = self.row(y);
+            for (k, &word) in row.iter().enumerate().take((xend - 1) / WORD_BITS + 1).skip(xstart / WORD_BITS) {
+                // Iterate through the live cells of the word
+                let mut word = word;
+                while word != 0 {
+                    let x = k * WORD_BITS + word.trailing_zeros() as usize;
+                    word &= word - 1;
+
+                    // Skip the cell if it is not visible
+                    if x < xstart || x >= xend {
+                        continue;
+                    }
+
+                    // Add the cell fill to the mesh builder
+                    mb.rectangle(
+                        graphics::DrawMode::Fill(graphics::FillOptions::default()),
+                        graphics::Rect::new((x as f32) * cellsize, (y as f32) * cellsize, cellsize, cellsize),
+                        [1.0, 1.0, 1.0, 1.0].into(),
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Serialize, Deserialize};
+
+use crate::Error;
+
+/// An enum that represents the policy for positions beyond the edges of a grid.
+///
+/// Possibile states:
+/// - ``Boundary::Dead`` <- positions beyond the edges do not exist (the default)
+/// - ``Boundary::Toroidal`` <- positions wrap around to the opposite edge
+/// - ``Boundary::Reflective`` <- positions are mirrored back across the edge
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Boundary {
+    /// Represents edges beyond which all cells are dead.
+    #[default]
+    Dead,
+    /// Represents edges that wrap around to the opposite edge.
+    Toroidal,
+    /// Represents edges that mirror the grid back onto itself.
+    Reflective,
+}
+
+/// Implementation of methods for Boundary
+impl Boundary {
+    /// Represents the names of all the boundary policies.
+    pub const NAMES: [&'static str; 3] = ["dead", "toroidal", "reflective"];
+
+    /// A method that resolves the given position onto a grid of the given
+    /// dimensions (in cells) according to the policy. Returns None if the
+    /// position lies beyond a dead edge or the grid has no cells.
+    pub fn resolve(&self, x: isize, y: isize, width: usize, height: usize) -> Option<(usize, usize)> {
+        // Check if the grid has any cells
+        if width == 0 || height == 0 {
+            return None;
+        }
+
+        // Resolve each axis independently
+        Some((self.resolve_axis(x, width)?, self.resolve_axis(y, height)?))
+    }
+
+    // A method that resolves a single coordinate onto an axis of the given length.
+    fn resolve_axis(&self, value: isize, length: usize) -> Option<usize> {
+        let length = length as isize;
+        match self {
+            // Coordinates beyond the edge do not exist
+            Boundary::Dead => match value >= 0 && value < length {
+                true => Some(value as usize),
+                false => None,
+            },
+            // Coordinates wrap around to the opposite edge
+            Boundary::Toroidal => Some(value.rem_euclid(length) as usize),
+            // Coordinates are mirrored across the edge (-1 maps to 0 and length maps to length-1)
+            Boundary::Reflective => {
+                let folded = value.rem_euclid(2 * length);
+                match folded < length {
+                    true => Some(folded as usize),
+                    false => Some((2 * length - 1 - folded) as usize),
+                }
+            },
+        }
+    }
+}
+
+/// Implementation of the Display trait for Boundary
+impl fmt::Display for Boundary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Boundary::Dead => write!(f, "dead"),
+            Boundary::Toroidal => write!(f, "toroidal"),
+            Boundary::Reflective => write!(f, "reflective"),
+        }
+    }
+}
+
+/// Implementation of the FromStr trait for Boundary
+impl FromStr for Boundary {
+    type Err = Error;
+
+    /// A method that parses a boundary from its name.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "dead" => Ok(Boundary::Dead),
+            "toroidal" => Ok(Boundary::Toroidal),
+            "reflective" => Ok(Boundary::Reflective),
+            _ => Err(Error::InvalidBoundary(value.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commons::grids::Boundary;
+
+    #[test]
+    fn resolve_axis() {
+        // Represents the positions on an axis of length 5 and where each boundary resolves them
+        let table: &[(isize, Option<usize>, usize, usize)] = &[
+            // (position, dead, toroidal, reflective)
+            (0, Some(0), 0, 0),
+            (4, Some(4), 4, 4),
+            (-1, None, 4, 0),
+            (5, None, 0, 4),
+            (-2, None, 3, 1),
+            (6, None, 1, 3),
+            (-5, None, 0, 4),
+            (10, None, 0, 0),
+            (-11, None, 4, 0),
+            (1_000_003, None, 3, 3),
+            (-1_000_003, None, 2, 2),
+        ];
+
+        for &(position, dead, toroidal, reflective) in table {
+            // Check each axis, with the other axis within the grid
+            assert_eq!(Boundary::Dead.resolve(position, 2, 5, 5), dead.map(|x| (x, 2)), "dead {}", position);
+            assert_eq!(Boundary::Dead.resolve(2, position, 5, 5), dead.map(|y| (2, y)), "dead {}", position);
+            assert_eq!(Boundary::Toroidal.resolve(position, 2, 5, 5), Some((toroidal, 2)), "toroidal {}", position);
+            assert_eq!(Boundary::Toroidal.resolve(2, position, 5, 5), Some((2, toroidal)), "toroidal {}", position);
+            assert_eq!(Boundary::Reflective.resolve(position, 2, 5, 5), Some((reflective, 2)), "reflective {}", position);
+            assert_eq!(Boundary::Reflective.resolve(2, position, 5, 5), Some((2, reflective)), "reflective {}", position);
+        }
+    }
+
+    #[test]
+    fn resolve_edges() {
+        for &boundary in &[Boundary::Dead, Boundary::Toroidal, Boundary::Reflective] {
+            // Far out positions and the extremes of the position type resolve onto the grid (or not at all)
+            for &(x, y) in &[(isize::MIN, 0), (0, isize::MAX), (isize::MAX, isize::MIN)] {
+                if let Some((rx, ry)) = boundary.resolve(x, y, 7, 3) {
+                    assert!(rx < 7 && ry < 3, "{} ({}, {})", boundary, x, y);
+                }
+            }
+
+            // A grid without cells resolves no positions
+            assert_eq!(boundary.resolve(0, 0, 0, 5), None, "{}", boundary);
+            assert_eq!(boundary.resolve(0, 0, 5, 0), None, "{}", boundary);
+
+            // A grid of a single cell resolves every position onto it (unless dead)
+            let expected = match boundary {
+                Boundary::Dead => None,
+                _ => Some((0, 0)),
+            };
+            assert_eq!(boundary.resolve(-1, 1, 1, 1), expected, "{}", boundary);
+        }
+    }
+
+    #[test]
+    fn parse_names() {
+        // Check that every name is parsed back from its display
+        for name in Boundary::NAMES.iter() {
+            assert_eq!(name.parse::<Boundary>().unwrap().to_string(), *name);
+        }
+        assert!("Dead".parse::<Boundary>().is_err());
+        assert!("wrap".parse::<Boundary>().is_err());
+    }
+}
@@ -1,20 +1,38 @@
+use std::ops::{Index, IndexMut};
+
 use rand::Rng;
-use ggez::graphics;
+use serde::{Serialize, Deserialize};
 
+use crate::{Error, Result};
+use crate::commons::bands;
+use crate::commons::cells::BinaryCell;
 use crate::commons::navigation::Direction4;
 use crate::simulation::{SimCell, SimGrid};
-use crate::commons::grids::{GridCell, GridScanner};
+use crate::commons::grids::{self, Boundary, GridCell, GridPoint, GridScanner, Neighbourhood, Neighbours};
 
 /// A struct that represents a grid of generic cells.
 /// The generic cell type must implement the SimCell trait.
-#[derive(Debug)]
+///
+/// The cells are stored in a single vector in row-major order, i.e. the cell
+/// at (x, y) is ``cells[y * width + x]``. The grid is indexed as ``grid[(x, y)]``.
+///
+/// The grid is double-buffered. A step computes the next state of every row into
+/// the back buffer and swaps it with the front buffer, so stepping does not allocate.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct CellGrid<T> where T: SimCell {
-    /// Represents the size of a single cell
-    cellsize: f32,
-    /// Represents the 2D vector of grid cells
-    pub vector: Option<Vec<Vec<T>>>,
-    /// Represents the 2D bounds of the grid
-    pub dimensions: Option<graphics::Rect>,
+    /// Represents the number of columns of the grid
+    width: usize,
+    /// Represents the number of rows of the grid
+    height: usize,
+    /// Represents the cells of the grid in row-major order (the front buffer)
+    cells: Vec<T>,
+    /// Represents the cells of the next step of the grid (the back buffer)
+    #[serde(skip, default = "Vec::new")]
+    back: Vec<T>,
+    /// Represents the policy for positions beyond the edges of the grid
+    #[serde(default)]
+    pub boundary: Boundary,
 }
 
 /// Implementation of the SimGrid trait for CellGrid
@@ -26,70 +44,283 @@ impl<T: SimCell> SimGrid for CellGrid<T> {
     type Orientation = Direction4;
 
     /// A constructor method that creates a null grid.
-    /// Set the given cell size into the struct.
-    fn new(cellsize: f32) -> Self {
+    fn new() -> Self {
         Self {
-            cellsize,
-            vector: None,
-            dimensions: None,
+            width: 0,
+            height: 0,
+            cells: Vec::new(),
+            back: Vec::new(),
+            boundary: Boundary::default(),
         }
     }
 
-    /// A setter method that sets the given grid vector to the struct.
-    fn setgrid(&mut self, other: Vec<Vec<Self::Cell>>) {
-        self.vector = Some(other);
+    /// A getter method that returns the name of the type of grid.
+    /// Ex: "Grid"
+    fn name(&self) -> String {
+        "Grid".to_string()
+    }
+
+    /// A setter method that sets the cells of the given grid to the struct.
+    /// The boundary policy of the struct is kept.
+    fn setgrid(&mut self, other: CellGrid<Self::Cell>) {
+        self.width = other.width;
+        self.height = other.height;
+        self.cells = other.cells;
     }
 
-    /// A setter method that sets the given grid dimensions to the struct.
-    fn setdimensions(&mut self, other: graphics::Rect) {
-        self.dimensions = Some(other);
+    /// A setter method that sets the cell at the given position.
+    /// Returns false if the grid is null or the position is out of bounds.
+    fn setcell(&mut self, x: isize, y: isize, cell: Self::Cell) -> bool {
+        match grids::unsigned(x, y).and_then(|(x, y)| self.offset(x, y)) {
+            None => false,
+            Some(index) => {
+                self.cells[index] = cell;
+                true
+            }
+        }
     }
 
     /// A getter method that returns the height of the grid (number of rows)
     /// Returns 0 if the grid is null.
     fn getheight(&self) -> usize {
-        match &self.vector {
-            None => 0,
-            Some(vec) => vec.len(),
-        }
+        self.height
     }
 
     /// A getter method that returns the width of the grid (number of columns).
     /// Returns 0 if the grid is null.
     fn getwidth(&self) -> usize {
-        match &self.vector {
-            None => 0,
-            Some(vec) => vec[0].len(),
-        }
+        self.width
+    }
+
+    /// A getter method that returns the cell at the given position.
+    /// Returns None if the grid is null or the position is out of bounds.
+    fn getcell(&self, x: isize, y: isize) -> Option<Self::Cell> {
+        grids::unsigned(x, y).and_then(|(x, y)| self.offset(x, y)).map(|index| self.cells[index])
+    }
+
+    /// A getter method that returns the boundary policy of the grid.
+    fn boundary(&self) -> Boundary {
+        self.boundary
+    }
+
+    /// A setter method that sets the boundary policy of the grid.
+    fn setboundary(&mut self, boundary: Boundary) {
+        self.boundary = boundary;
     }
 
     /// A method that returns a random cell from the grid.
-    /// Returns the x,y position of the cell along with cell state as a GridCell
-    fn randomcell(&self) -> GridCell<Self::Cell> {
-        // Check if grid exists
-        if let Some(grid) = &self.vector {
-            // Get a random column and row from the grid
-            let col = rand::thread_rng().gen_range(0..grid.len());
-            let row = rand::thread_rng().gen_range(0..grid[0].len());
-            // Build the GridCell and return it
-            return (col, row, grid[row][col])
-        
-        // If the grid is null, panic
-        } else {
-            panic!("random grid cell selection failed. grid is empty!")
+    /// Returns the x,y position of the cell along with cell state as a GridPoint
+    /// or an error if the grid is null or has no cells.
+    fn randomcell<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<GridPoint<Self::Cell>> {
+        // Check if the grid has any cells
+        let (width, height) = (self.getwidth(), self.getheight());
+        if width == 0 || height == 0 {
+            return Err(Error::EmptyGrid);
+        }
+
+        // Get a random column and row from the grid
+        let col = rng.gen_range(0..width);
+        let row = rng.gen_range(0..height);
+        // Build the GridPoint and return it
+        let (col, row) = (col as isize, row as isize);
+        self.getcell(col, row).map(|cell| (col, row, cell)).ok_or(Error::EmptyGrid)
+    }
+
+    /// A method that returns the state in the given previous grid
+    /// of every cell that differs between it and the current grid.
+    fn changes(&self, previous: &Self) -> Vec<GridPoint<Self::Cell>> {
+        // Compare the cells directly if the grids have the same dimensions
+        if (self.width, self.height) == (previous.width, previous.height) {
+            return previous.iter()
+                .zip(self.cells.iter())
+                .filter(|((_, _, old), new)| old != new)
+                .map(|((x, y, &cell), _)| (x as isize, y as isize, cell))
+                .collect();
         }
-    } 
+
+        // Otherwise look up every cell of the previous grid
+        previous.iter()
+            .map(|(x, y, &cell)| (x as isize, y as isize, cell))
+            .filter(|&(x, y, cell)| self.getcell(x, y) != Some(cell))
+            .collect()
+    }
+}
+
+/// Implementation of helper methods for CellGrid<T>.
+impl<T: SimCell> CellGrid<T> {
+    /// A method that resolves the given position (which may lie beyond the edges
+    /// of the grid) onto a cell of the grid according to its boundary policy.
+    /// Returns None if the position does not map onto any cell.
+    pub fn resolve(&self, x: isize, y: isize) -> Option<(usize, usize)> {
+        self.boundary.resolve(x, y, self.width, self.height)
+    }
+
+    /// A constructor function that creates a grid with the given dimensions (in cells)
+    /// whose cells are the values of the given function at their positions.
+    /// The function is called for each position in row-major order.
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(width: usize, height: usize, mut f: F) -> Self {
+        let mut grid = Self::new();
+        grid.width = width;
+        grid.height = height;
+        grid.cells = (0..width * height).map(|index| f(index % width, index / width)).collect();
+        grid
+    }
+
+    /// A method that returns the index in the cell vector of the given position.
+    /// Returns None if the position is out of bounds.
+    pub fn offset(&self, x: usize, y: usize) -> Option<usize> {
+        match x < self.width && y < self.height {
+            true => Some(y * self.width + x),
+            false => None,
+        }
+    }
+
+    /// A method that returns an iterator over the positions and cells
+    /// of the grid as ``(x, y, &cell)`` in row-major order.
+    pub fn iter(&self) -> GridScanner<std::slice::Iter<'_, T>> {
+        GridScanner::new(self.cells.iter(), self.width)
+    }
+
+    /// A method that returns an iterator over the neighbours of the cell at the given position
+    /// in the given neighbourhood as ``(x, y, &cell)``, resolved with the boundary policy of the grid.
+    pub fn neighbours<'a>(&'a self, x: usize, y: usize, neighbourhood: &'a Neighbourhood) -> Neighbours<'a, T> {
+        Neighbours::new(self, x, y, neighbourhood)
+    }
+
+    /// A method that returns an iterator over the rows of the grid as slices of cells.
+    pub fn rows(&self) -> std::slice::Chunks<'_, T> {
+        // A null grid has no cells, so any chunk size yields no rows
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// A getter method that returns the cells of the grid in row-major order.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// A method that resizes the grid to the given dimensions (in cells). The cells that remain
+    /// within the grid keep their positions (anchored to the top left corner) and the new cells
+    /// are set to the given cell. The back buffer is released and reallocated on the next step.
+    pub fn resize(&mut self, width: usize, height: usize, fill: T) {
+        // Take the cells of the grid before it is resized
+        let previous = std::mem::take(&mut self.cells);
+        let (oldwidth, oldheight) = (self.width, self.height);
+        // Calculate the number of columns of each row that are kept
+        let kept = width.min(oldwidth);
+
+        // Build the cells of the resized grid a row at a time
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            // Copy the kept cells of the row if it was within the grid
+            if y < oldheight {
+                cells.extend_from_slice(&previous[y * oldwidth..y * oldwidth + kept]);
+            }
+            // Fill the rest of the row with the given cell
+            cells.resize((y + 1) * width, fill);
+        }
+
+        // Set the resized cells and dimensions of the grid
+        self.cells = cells;
+        self.width = width;
+        self.height = height;
+        self.back = Vec::new();
+    }
+
+    /// A method that steps the grid with the given function of the grid, a row index and the
+    /// row to fill, and returns the results of the rows combined with the given function.
+    pub fn step<S, F, C>(&mut self, f: F, combine: C) -> S
+    where
+        T: Send + Sync,
+        S: Default + Send,
+        F: Fn(&Self, usize, &mut [T]) -> S + Sync,
+        C: Fn(S, S) -> S + Sync,
+    {
+        // Take the back buffer and size it to the front buffer
+        let mut back = std::mem::take(&mut self.back);
+        if back.len() != self.cells.len() {
+            back = self.cells.clone();
+        }
+
+        // Compute the next state of every row into the back buffer
+        let grid = &*self;
+        let result = bands::step_rows(&mut back, self.width, |y, row| f(grid, y, row), combine);
+
+        // Swap the buffers and keep the previous cells as the next back buffer
+        self.back = std::mem::replace(&mut self.cells, back);
+        result
+    }
+}
+
+/// Implementation of helper methods for CellGrid<BinaryCell>.
+impl CellGrid<BinaryCell> {
+    /// A method that counts the cells of the grid by state.
+    /// Returns the number of active and passive cells as a tuple.
+    pub fn population(&self) -> (u32, u32) {
+        // Declare counter variables for the number of active and passive cells
+        let mut active: u32 = 0;
+        let mut passive: u32 = 0;
+
+        // Iterate over the cells of the grid and count their states
+        for cell in &self.cells {
+            match cell {
+                BinaryCell::Active => active += 1,
+                BinaryCell::Passive => passive += 1,
+            }
+        }
+
+        // Return the counter values
+        (active, passive)
+    }
 }
 
 /// Implementation of the Clone trait for Grid
 impl<T: SimCell> Clone for CellGrid<T> {
-    /// A method that returns a new CellGrid with the same  
-    /// vector and properties as the current CellGrid.
+    /// A method that returns a new CellGrid with the same
+    /// cells and properties as the current CellGrid.
+    /// The back buffer is not cloned, as it only holds scratch cells.
     fn clone(&self) -> Self {
         Self {
-            cellsize: self.cellsize,
-            vector: self.vector.clone(),
-            dimensions: self.dimensions.clone(),
+            width: self.width,
+            height: self.height,
+            cells: self.cells.clone(),
+            back: Vec::new(),
+            boundary: self.boundary,
+        }
+    }
+
+    /// A method that copies the cells and properties of the given CellGrid
+    /// into the current CellGrid, reusing the allocation of its cells.
+    fn clone_from(&mut self, source: &Self) {
+        self.width = source.width;
+        self.height = source.height;
+        self.cells.clone_from(&source.cells);
+        self.boundary = source.boundary;
+    }
+}
+
+/// Implementation of the Index trait for Grid
+impl<T: SimCell> Index<(usize, usize)> for CellGrid<T> {
+    /// Define the type of the indexed value
+    type Output = T;
+
+    /// A method that returns a reference to the cell at the given (x, y) position.
+    /// Panics if the position is out of bounds.
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        match self.offset(x, y) {
+            Some(index) => &self.cells[index],
+            None => panic!("position ({}, {}) is out of bounds for a {}x{} grid", x, y, self.width, self.height),
+        }
+    }
+}
+
+/// Implementation of the IndexMut trait for Grid
+impl<T: SimCell> IndexMut<(usize, usize)> for CellGrid<T> {
+    /// A method that returns a mutable reference to the cell at the given (x, y) position.
+    /// Panics if the position is out of bounds.
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        match self.offset(x, y) {
+            Some(index) => &mut self.cells[index],
+            None => panic!("position ({}, {}) is out of bounds for a {}x{} grid", x, y, self.width, self.height),
         }
     }
 }
@@ -99,23 +330,77 @@ impl<T: SimCell> IntoIterator for CellGrid<T> {
     /// Define the iteration item type
     type Item = GridCell<T>;
     /// Define the iterator type
-    type IntoIter = GridScanner<CellGrid<T>>;
+    type IntoIter = GridScanner<std::vec::IntoIter<T>>;
+
+    /// A method that creates an iterator that consumes the CellGrid.
+    /// A null grid creates an iterator without any cells.
+    fn into_iter(self) -> Self::IntoIter {
+        GridScanner::new(self.cells.into_iter(), self.width)
+    }
+}
+
+/// Implementation of the IntoIterator trait for a reference to a Grid
+impl<'a, T: SimCell> IntoIterator for &'a CellGrid<T> {
+    /// Define the iteration item type
+    type Item = (usize, usize, &'a T);
+    /// Define the iterator type
+    type IntoIter = GridScanner<std::slice::Iter<'a, T>>;
 
-    /// A method that creates an iterator for the CellGrid.
+    /// A method that creates an iterator that borrows the cells of the CellGrid.
     fn into_iter(self) -> Self::IntoIter {
-        // Check the option value
-        match self.vector {
-            // Panic if GetOption::None is returned
-            None => panic!("could not create grid iterator!"),
-            // Create GridIterator with the grid
-            Some(gridvector) => {
-                GridScanner::<CellGrid<T>> {
-                    gridvector,
-                    current_column: 0,
-                    current_row: 0,
-                }
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::simulation::SimGrid;
+    use crate::commons::cells::BinaryCell;
+    use crate::commons::grids::CellGrid;
+
+    // A function that returns the cell of the original grid at the given position
+    fn original(x: usize, y: usize) -> BinaryCell {
+        match (x * 7 + y * 3).is_multiple_of(4) {
+            true => BinaryCell::Active,
+            false => BinaryCell::Passive,
+        }
+    }
+
+    // A function that checks that the given grid has the cells of the original grid of the
+    // given size anchored to its top left corner, with the rest of its cells set to the fill
+    fn check(grid: &CellGrid<BinaryCell>, (width, height): (usize, usize), fill: BinaryCell) {
+        for y in 0..grid.getheight() {
+            for x in 0..grid.getwidth() {
+                let expected = match x < width && y < height {
+                    true => original(x, y),
+                    false => fill,
+                };
+                assert_eq!(grid[(x, y)], expected, "cell ({}, {}) of {}x{}", x, y, grid.getwidth(), grid.getheight());
             }
         }
+        assert_eq!(grid.cells().len(), grid.getwidth() * grid.getheight());
     }
-}
 
+    #[test]
+    fn resize_keeps_top_left() {
+        // Shrink the width and grow the height, then grow the width and shrink the height
+        let mut grid = CellGrid::from_fn(5, 4, original);
+        grid.resize(3, 6, BinaryCell::Passive);
+        check(&grid, (3, 4), BinaryCell::Passive);
+        grid.resize(8, 2, BinaryCell::Active);
+        assert_eq!((grid.getwidth(), grid.getheight()), (8, 2));
+        check(&grid, (3, 4), BinaryCell::Active);
+
+        // Grow both dimensions with the new cells set to the fill
+        let mut grid = CellGrid::from_fn(5, 4, original);
+        grid.resize(9, 7, BinaryCell::Active);
+        check(&grid, (5, 4), BinaryCell::Active);
+
+        // A grid resized to the same size is unchanged and a grid without cells is emptied
+        grid.resize(9, 7, BinaryCell::Passive);
+        check(&grid, (5, 4), BinaryCell::Active);
+        grid.resize(0, 3, BinaryCell::Passive);
+        assert!(grid.cells().is_empty());
+        assert_eq!(grid.rows().count(), 0);
+    }
+}
@@ -1,71 +1,60 @@
-use ggez::graphics;
+use rand::Rng;
 
+use crate::{Error, Result};
 use crate::simulation::SimCell;
 use crate::commons::cells::BinaryCell;
-use crate::commons::grids::CellGrid;
+use crate::commons::grids::{CellGrid, Pattern, Placement};
 
 /// Implementation of builder methods for CellGrid<T>.
 /// A collection of functions that build various intial states of the grid.
 impl<T: SimCell> CellGrid<T> {
-    /// A function that creates a randomized grid of cells for the given cell size and grid dimensions. 
-    /// All cell states have an equal probability of occuring on the grid.
-    pub fn generate_randomgrid_balanced(cellsize: f32, dimensions:graphics::Rect) -> Vec<Vec<T>> {
-        // Calculate the number of rows and columns in the grid
-        let rows = dimensions.w / cellsize;
-        let cols = dimensions.h / cellsize;
-
-        // Create a new vector (represents rows)
-        let mut gridvector = Vec::new();
-
-        // Iterate for each row
-        for _ in 0..(rows as i32) {
-            // Create a new vector (represents columns)
-            let mut column = Vec::new();
-            // Iterate for each column
-            for _ in 0..(cols as i32) {
-                // Create a new cell with the balanced randomizer 
-                // and push it into the column vector
-                column.push(T::balanced());
-            }
-
-            // Push the column vector into the row vector (grid)
-            gridvector.push(column);
-        }  
-
-        // Return the grid vector
-        return gridvector
+    /// A function that creates a randomized grid of cells for the given grid dimensions (in cells)
+    /// from the given generator. All cell states have an equal probability of occuring on the grid.
+    /// Returns an error if the grid dimensions have no cells.
+    pub fn generate_randomgrid_balanced<R: Rng + ?Sized>(width: usize, height: usize, rng: &mut R) -> Result<CellGrid<T>> {
+        // Check the grid dimensions
+        check_dimensions(width, height)?;
+
+        // Create the cells with the balanced randomizer one column at a time
+        // (the order in which cells are generated for a given seed)
+        let columns: Vec<T> = (0..width * height).map(|_| T::balanced(rng)).collect();
+
+        // Create the grid from the cells of the columns
+        Ok(CellGrid::from_fn(width, height, |x, y| columns[x * height + y]))
     }
 }
 
 /// Implementation of builder methods for CellGrid<BinaryCell>.
 /// A collection of functions that build various intial states of the grid with binary cells.
 impl CellGrid<BinaryCell> {
-    /// A function that creates an empty grid of cells for the given cell size and grid dimensions.
+    /// A function that creates an empty grid of cells for the given grid dimensions (in cells).
     /// All cell states are set to BinaryCell::Passive.
-    pub fn generate_empty_grid(cellsize: f32, dimensions:graphics::Rect) -> Vec<Vec<BinaryCell>> {
-        // Calculate the number of rows and columns in the grid
-        let rows = dimensions.w / cellsize;
-        let cols = dimensions.h / cellsize;
+    /// Returns an error if the grid dimensions have no cells.
+    pub fn generate_empty_grid(width: usize, height: usize) -> Result<CellGrid<BinaryCell>> {
+        // Check the grid dimensions
+        check_dimensions(width, height)?;
 
-        // Create a new vector (represents rows)
-        let mut gridvector = Vec::new();
+        // Create a grid of passive cells
+        Ok(CellGrid::from_fn(width, height, |_, _| BinaryCell::Passive))
+    }
 
-        // Iterate for each row
-        for _ in 0..(rows as i32) {
-            // Create a new vector (represents columns)
-            let mut column = Vec::new();
-            // Iterate for each column
-            for _ in 0..(cols as i32) {
-                // Create a new cell with the balanced randomizer 
-                // and push it into the column vector
-                column.push(BinaryCell::Passive);
-            }
+    /// A function that creates an empty grid of cells for the given grid dimensions (in cells)
+    /// with the given pattern placed onto it. Cells of the pattern outside the grid are clipped.
+    /// Returns an error if the grid dimensions have no cells.
+    pub fn generate_pattern_grid(width: usize, height: usize, pattern: &Pattern, placement: Placement) -> Result<CellGrid<BinaryCell>> {
+        // Create an empty grid and place the pattern onto it
+        let mut grid = Self::generate_empty_grid(width, height)?;
+        grid.place(pattern, placement);
 
-            // Push the column vector into the row vector (grid)
-            gridvector.push(column);
-        }  
+        // Return the grid
+        Ok(grid)
+    }
+}
 
-        // Return the grid vector
-        return gridvector
+// A function that checks that the given grid dimensions (in cells) hold at least one cell.
+fn check_dimensions(width: usize, height: usize) -> Result<()> {
+    match width == 0 || height == 0 {
+        true => Err(Error::InvalidDimensions { width, height }),
+        false => Ok(()),
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,43 @@
+use crate::commons::grids::cellgrid::Pattern;
+
+/// Represents the catalogue of built-in patterns as (name, display name, RLE body).
+const LIBRARY: &[(&str, &str, &str)] = &[
+    // Still lifes and oscillators
+    ("block", "Block", "2o$2o!"),
+    ("blinker", "Blinker", "3o!"),
+    ("toad", "Toad", "b3o$3o!"),
+    ("beacon", "Beacon", "2o$2o$2b2o$2b2o!"),
+    ("pulsar", "Pulsar", "2b3o3b3o2$o4bobo4bo$o4bobo4bo$o4bobo4bo$2b3o3b3o2$2b3o3b3o$o4bobo4bo$o4bobo4bo$o4bobo4bo2$2b3o3b3o!"),
+    ("pentadecathlon", "Pentadecathlon", "2bo4bo$2ob4ob2o$2bo4bo!"),
+    // Spaceships
+    ("glider", "Glider", "bo$2bo$3o!"),
+    ("lwss", "Lightweight Spaceship", "bo2bo$o$o3bo$4o!"),
+    ("mwss", "Middleweight Spaceship", "3bo$bo3bo$o$o4bo$5o!"),
+    ("hwss", "Heavyweight Spaceship", "3b2o$bo4bo$o$o5bo$6o!"),
+    // Methuselahs
+    ("r-pentomino", "R-pentomino", "b2o$2o$bo!"),
+    ("acorn", "Acorn", "bo$3bo$2o2b3o!"),
+    ("diehard", "Diehard", "6bo$2o$bo3b3o!"),
+    // Guns
+    ("gosper-glider-gun", "Gosper Glider Gun", "24bo$22bobo$12b2o6b2o12b2o$11bo3bo4b2o12b2o$2o8bo5bo3b2o$2o8bo3bob2o4bobo$10bo5bo7bo$11bo3bo$12b2o!"),
+];
+
+/// Implementation of the built-in pattern library for Pattern.
+impl Pattern {
+    /// A function that returns the names of all the built-in patterns.
+    pub fn builtin_names() -> Vec<&'static str> {
+        LIBRARY.iter().map(|&(name, _, _)| name).collect()
+    }
+
+    /// A function that returns the built-in pattern with the given name
+    /// (ex: "glider", "gosper-glider-gun"). Returns None if there is no such pattern.
+    pub fn builtin(name: &str) -> Option<Self> {
+        let &(_, display, body) = LIBRARY.iter().find(|&&(n, _, _)| n == name)?;
+
+        // Parse the pattern from its RLE body (the library is known to be valid)
+        let mut pattern = Self::from_rle(body).ok()?;
+        pattern.name = Some(display.to_string());
+        pattern.rule = Some("B3/S23".to_string());
+        Some(pattern)
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{Error, Result};
+use crate::commons::grids::cellgrid::Pattern;
+
+/// Represents the header line of the Life 1.06 format.
+const LIFE106_HEADER: &str = "#Life 1.06";
+
+/// Implementation of the Life 1.06 (.lif, .life) format for Pattern.
+///
+/// A Life 1.06 pattern starts with the ``#Life 1.06`` header line followed
+/// by the ``x y`` coordinates of each alive cell, one cell per line.
+/// Coordinates may be negative and are moved so that the top left
+/// corner of the bounding box of the cells is at (0, 0).
+impl Pattern {
+    /// A function that parses a pattern in the Life 1.06 format.
+    pub fn from_life106(text: &str) -> Result<Self> {
+        let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty());
+
+        // Check the header line
+        if lines.next() != Some(LIFE106_HEADER) {
+            return Err(invalid("missing '#Life 1.06' header"));
+        }
+
+        // Parse the coordinates of the alive cells
+        let mut coordinates: Vec<(i64, i64)> = Vec::new();
+        let mut comments = Vec::new();
+        for line in lines {
+            // Keep any comment lines
+            if let Some(comment) = line.strip_prefix('#') {
+                comments.push(comment.trim().to_string());
+                continue;
+            }
+
+            let values = line.split_whitespace().map(str::parse::<i64>).collect::<Vec<_>>();
+            match values.as_slice() {
+                [Ok(x), Ok(y)] => coordinates.push((*x, *y)),
+                _ => return Err(invalid(&format!("invalid coordinates '{}'", line))),
+            }
+        }
+
+        // Move the cells so that their bounding box starts at (0, 0)
+        let left = coordinates.iter().map(|&(x, _)| x).min().unwrap_or(0);
+        let top = coordinates.iter().map(|&(_, y)| y).min().unwrap_or(0);
+        let cells = coordinates.into_iter()
+            .map(|(x, y)| ((x - left) as usize, (y - top) as usize))
+            .collect();
+
+        let mut pattern = Pattern::new(cells);
+        pattern.comments = comments;
+        Ok(pattern)
+    }
+
+    /// A method that writes the pattern in the Life 1.06 format.
+    pub fn to_life106(&self) -> String {
+        let mut text = format!("{}\n", LIFE106_HEADER);
+        for (x, y) in &self.cells {
+            text.push_str(&format!("{} {}\n", x, y));
+        }
+
+        text
+    }
+}
+
+// A function that creates an error for an invalid Life 1.06 pattern.
+fn invalid(reason: &str) -> Error {
+    Error::InvalidPattern(format!("invalid life 1.06 pattern. {}", reason))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commons::grids::Pattern;
+
+    #[test]
+    fn builtin_round_trip() {
+        // Check that the cells of every built-in pattern are parsed back from its Life 1.06 form
+        for name in Pattern::builtin_names() {
+            let pattern = Pattern::builtin(name).unwrap();
+            let parsed = Pattern::from_life106(&pattern.to_life106()).unwrap();
+            assert_eq!((parsed.width, parsed.height, &parsed.cells), (pattern.width, pattern.height, &pattern.cells), "pattern {}", name);
+        }
+    }
+
+    #[test]
+    fn parse_coordinates() {
+        // Negative coordinates are moved to the top left corner and comment lines are kept
+        let pattern = Pattern::from_life106("#Life 1.06\n#D A glider\n0 -1\n\n1 0\n -1 1\n0 1\n1 1\n").unwrap();
+        assert_eq!(pattern.comments, vec!["D A glider"]);
+        assert_eq!((pattern.width, pattern.height), (3, 3));
+        assert_eq!(pattern.cells, vec![(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]);
+
+        // A pattern without cells is empty
+        let empty = Pattern::from_life106("#Life 1.06\n").unwrap();
+        assert_eq!((empty.width, empty.height, empty.cells.len()), (0, 0, 0));
+    }
+
+    #[test]
+    fn reject_invalid() {
+        for text in ["", "0 0\n", "#Life 1.05\n0 0\n", "#Life 1.06\n0\n", "#Life 1.06\n0 0 0\n", "#Life 1.06\nx 0\n"] {
+            assert!(Pattern::from_life106(text).is_err(), "{:?}", text);
+        }
+    }
+}
@@ -1,4 +1,12 @@
 pub mod core;
 pub mod gridgen;
+pub mod pattern;
+pub mod rle;
+pub mod plaintext;
+pub mod life106;
+pub mod library;
+#[cfg(feature = "graphics")]
+pub mod render;
 
-pub use crate::commons::grids::cellgrid::core::CellGrid;
\ No newline at end of file
+pub use crate::commons::grids::cellgrid::core::CellGrid;
+pub use crate::commons::grids::cellgrid::pattern::{Pattern, Placement};
@@ -0,0 +1,162 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use crate::{Error, Result};
+use crate::simulation::SimGrid;
+use crate::commons::cells::BinaryCell;
+use crate::commons::grids::{CellGrid, GridPoint};
+
+/// A struct that represents a pattern of live cells that can be
+/// placed onto a grid or read from and written to pattern files.
+///
+/// The positions of the live cells are relative to the top left corner of the
+/// bounding box of the pattern, with x increasing to the right and y downward.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Pattern {
+    /// Represents the name of the pattern, if any.
+    pub name: Option<String>,
+    /// Represents the comment lines of the pattern.
+    pub comments: Vec<String>,
+    /// Represents the rule of the pattern in birth/survival notation, if any.
+    pub rule: Option<String>,
+    /// Represents the width of the bounding box of the pattern (in cells).
+    pub width: usize,
+    /// Represents the height of the bounding box of the pattern (in cells).
+    pub height: usize,
+    /// Represents the positions of the live cells of the pattern.
+    pub cells: Vec<(usize, usize)>,
+}
+
+/// An enum that represents where a pattern is placed on a grid.
+///
+/// Possibile states:
+/// - ``Placement::Centre`` <- the pattern is centred on the grid
+/// - ``Placement::At(x, y)`` <- the top left corner of the pattern is placed at (x, y)
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Placement {
+    /// Represents a pattern centred on the grid.
+    #[default]
+    Centre,
+    /// Represents a pattern with its top left corner at the given position.
+    At(isize, isize),
+}
+
+/// Implementation of methods for Pattern
+impl Pattern {
+    /// A constructor function that creates an unnamed pattern with the given
+    /// live cells. The bounding box is the smallest one that holds every cell.
+    pub fn new(cells: Vec<(usize, usize)>) -> Self {
+        let width = cells.iter().map(|&(x, _)| x + 1).max().unwrap_or(0);
+        let height = cells.iter().map(|&(_, y)| y + 1).max().unwrap_or(0);
+        Self { width, height, cells, ..Self::default() }
+    }
+
+    /// A constructor function that creates a pattern from the live cells of the given grid.
+    /// If bounded is set, the pattern is cropped to the bounding box of the live cells,
+    /// otherwise the pattern spans the full grid.
+    pub fn from_grid(grid: &CellGrid<BinaryCell>, bounded: bool) -> Self {
+        // Collect the positions of the live cells of the grid
+        let cells: Vec<(usize, usize)> = grid.iter()
+            .filter(|&(_, _, &cell)| cell == BinaryCell::Active)
+            .map(|(x, y, _)| (x, y))
+            .collect();
+
+        // Check if the pattern spans the full grid
+        if !bounded {
+            return Self { width: grid.getwidth(), height: grid.getheight(), cells, ..Self::default() };
+        }
+
+        // Crop the cells to their bounding box
+        let left = cells.iter().map(|&(x, _)| x).min().unwrap_or(0);
+        let top = cells.iter().map(|&(_, y)| y).min().unwrap_or(0);
+        Self::new(cells.into_iter().map(|(x, y)| (x - left, y - top)).collect())
+    }
+
+    /// A method that returns the sorted x positions of the live cells of the pattern
+    /// grouped by their rows (in order). Rows without live cells are left out.
+    pub fn rows(&self) -> BTreeMap<usize, Vec<usize>> {
+        let mut rows: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        for &(x, y) in &self.cells {
+            rows.entry(y).or_default().push(x);
+        }
+
+        // Sort the cells of each row and drop any duplicates
+        for row in rows.values_mut() {
+            row.sort_unstable();
+            row.dedup();
+        }
+
+        rows
+    }
+
+    /// A function that reads a pattern from the file at the given path. The format is chosen
+    /// from the file extension (``.rle``, ``.cells`` or ``.lif``/``.life`` for Life 1.06).
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)?;
+        match extension(path).as_str() {
+            "rle" => Self::from_rle(&text),
+            "cells" => Self::from_plaintext(&text),
+            "lif" | "life" => Self::from_life106(&text),
+            other => Err(Error::InvalidPattern(format!("unsupported pattern format '{}'", other))),
+        }
+    }
+
+    /// A method that writes the pattern to the file at the given path. The format is chosen
+    /// from the file extension (``.rle``, ``.cells`` or ``.lif``/``.life`` for Life 1.06).
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let text = match extension(path).as_str() {
+            "rle" => self.to_rle(),
+            "cells" => self.to_plaintext(),
+            "lif" | "life" => self.to_life106(),
+            other => return Err(Error::InvalidPattern(format!("unsupported pattern format '{}'", other))),
+        };
+
+        fs::write(path, text)?;
+        Ok(())
+    }
+}
+
+// A function that returns the lowercase extension of the given path.
+fn extension(path: &Path) -> String {
+    path.extension().and_then(|e| e.to_str()).unwrap_or("").to_lowercase()
+}
+
+/// Implementation of pattern placement methods for CellGrid<BinaryCell>.
+impl CellGrid<BinaryCell> {
+    /// A method that returns the live cells that place the given pattern onto the grid.
+    /// Cells that fall outside the grid are clipped.
+    pub fn placement(&self, pattern: &Pattern, placement: Placement) -> Vec<GridPoint<BinaryCell>> {
+        pattern.clipped(placement, self.getwidth(), self.getheight())
+    }
+
+    /// A method that places the given pattern onto the grid.
+    /// Cells of the pattern that fall outside the grid are clipped.
+    pub fn place(&mut self, pattern: &Pattern, placement: Placement) {
+        for (x, y, cell) in self.placement(pattern, placement) {
+            self.setcell(x, y, cell);
+        }
+    }
+}
+
+/// Implementation of clipped placement methods for Pattern.
+impl Pattern {
+    /// A method that returns the live cells that place the pattern onto a bounded grid
+    /// of the given dimensions (in cells). Cells that fall outside the grid are clipped.
+    pub fn clipped(&self, placement: Placement, width: usize, height: usize) -> Vec<GridPoint<BinaryCell>> {
+        let (width, height) = (width as isize, height as isize);
+
+        // Calculate the position of the top left corner of the pattern
+        let (left, top) = match placement {
+            Placement::Centre => ((width - self.width as isize) / 2, (height - self.height as isize) / 2),
+            Placement::At(x, y) => (x, y),
+        };
+
+        // Collect the live cells that fall within the grid
+        self.cells.iter()
+            .map(|&(x, y)| (left + x as isize, top + y as isize))
+            .filter(|&(gx, gy)| gx >= 0 && gy >= 0 && gx < width && gy < height)
+            .map(|(gx, gy)| (gx, gy, BinaryCell::Active))
+            .collect()
+    }
+}
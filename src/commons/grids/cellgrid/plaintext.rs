@@ -0,0 +1,111 @@
+use crate::{Error, Result};
+use crate::commons::grids::cellgrid::Pattern;
+
+/// Implementation of the plaintext (.cells) format for Pattern.
+///
+/// A plaintext pattern has optional ``!`` comment lines (``!Name:`` gives the name)
+/// followed by one line per row of the pattern, with ``.`` for dead cells and ``O``
+/// for alive cells. Rows may be shorter than the pattern, the rest of them is dead.
+impl Pattern {
+    /// A function that parses a pattern in the plaintext format.
+    pub fn from_plaintext(text: &str) -> Result<Self> {
+        let mut pattern = Pattern::default();
+        let mut y = 0;
+
+        for line in text.lines().map(str::trim_end) {
+            // Parse the comment lines
+            if let Some(comment) = line.strip_prefix('!') {
+                match comment.strip_prefix("Name:") {
+                    Some(name) => pattern.name = Some(name.trim().to_string()),
+                    None => pattern.comments.push(comment.trim().to_string()),
+                }
+                continue;
+            }
+
+            // Parse the cells of the row
+            for (x, c) in line.chars().enumerate() {
+                match c {
+                    '.' => {},
+                    'O' | '*' => pattern.cells.push((x, y)),
+                    _ => return Err(Error::InvalidPattern(format!(
+                        "invalid plaintext pattern. unexpected character '{}' on row {}", c, y
+                    ))),
+                }
+            }
+
+            // Grow the pattern to hold the row
+            pattern.width = pattern.width.max(line.len());
+            y += 1;
+        }
+
+        pattern.height = y;
+        Ok(pattern)
+    }
+
+    /// A method that writes the pattern in the plaintext format.
+    pub fn to_plaintext(&self) -> String {
+        let mut text = String::new();
+
+        // Write the comment lines
+        if let Some(name) = &self.name {
+            text.push_str(&format!("!Name: {}\n", name));
+        }
+        for comment in &self.comments {
+            text.push_str(&format!("!{}\n", comment));
+        }
+
+        // Write the cells of each row
+        let rows = self.rows();
+        for y in 0..self.height {
+            let mut row = vec!['.'; self.width];
+            for &x in rows.get(&y).into_iter().flatten().filter(|&&x| x < self.width) {
+                row[x] = 'O';
+            }
+            text.extend(row);
+            text.push('\n');
+        }
+
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commons::grids::Pattern;
+
+    #[test]
+    fn builtin_round_trip() {
+        // Check that every built-in pattern is parsed back from its plaintext form (without its rule)
+        for name in Pattern::builtin_names() {
+            let mut pattern = Pattern::builtin(name).unwrap();
+            pattern.rule = None;
+            pattern.comments = vec!["a comment".to_string(), String::new()];
+            let parsed = Pattern::from_plaintext(&pattern.to_plaintext()).unwrap();
+            assert_eq!(parsed, pattern, "pattern {}", name);
+        }
+    }
+
+    #[test]
+    fn parse_comments() {
+        let text = "!Name: Glider\n!A spaceship\n!\n.O\n..O\n!between rows\nOOO\n";
+        let pattern = Pattern::from_plaintext(text).unwrap();
+
+        // Check that the name and comments are kept and the comment lines are not rows
+        assert_eq!(pattern.name.as_deref(), Some("Glider"));
+        assert_eq!(pattern.comments, vec!["A spaceship", "", "between rows"]);
+        assert_eq!((pattern.width, pattern.height), (3, 3));
+        assert_eq!(pattern.cells, vec![(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]);
+    }
+
+    #[test]
+    fn parse_rows() {
+        // Short and empty rows are dead and the alternative tag of alive cells is accepted
+        let pattern = Pattern::from_plaintext("*\n\n...*.\n").unwrap();
+        assert_eq!((pattern.width, pattern.height), (5, 3));
+        assert_eq!(pattern.cells, vec![(0, 0), (3, 2)]);
+
+        // Any other character is invalid
+        assert!(Pattern::from_plaintext(".O\nbo\n").is_err());
+        assert!(Pattern::from_plaintext("#N Glider\n.O\n").is_err());
+    }
+}
@@ -0,0 +1,55 @@
+use ggez::graphics;
+
+use crate::commons::cells::BinaryCell;
+use crate::simulation::SimGrid;
+use crate::commons::grids::CellGrid;
+
+/// Implementation of rendering methods for CellGrid<BinaryCell>.
+impl CellGrid<BinaryCell> {
+    /// A method that adds the cells of the grid within the visible region (in grid space)
+    /// to the given mesh builder. Active cells are filled white, passive cells are
+    /// filled black and every cell is outlined with a faint boundary.
+    pub fn render(&self, mb: &mut graphics::MeshBuilder, cellsize: f32, visible: graphics::Rect) {
+        // Calculate the range of columns and rows within the visible region
+        let (xstart, xend) = visible_range(visible.x, visible.w, cellsize, self.getwidth());
+        let (ystart, yend) = visible_range(visible.y, visible.h, cellsize, self.getheight());
+
+        // Iterate through each visible cell in the grid (a null grid has no rows)
+        for (y, row) in self.rows().enumerate().take(yend).skip(ystart) {
+            for (x, cell) in row.iter().enumerate().take(xend).skip(xstart) {
+                // Create the bounds of the cell
+                let cellbounds = graphics::Rect::new(
+                    (x as f32) * cellsize,
+                    (y as f32) * cellsize,
+                    cellsize,
+                    cellsize,
+                );
+
+                // Add the cell fill to the mesh builder
+                mb.rectangle(
+                    graphics::DrawMode::Fill(graphics::FillOptions::default()),
+                    cellbounds,
+                    // Set the cell color based on cell state
+                    match cell {
+                        BinaryCell::Passive => [0.0, 0.0, 0.0, 1.0].into(),
+                        BinaryCell::Active => [1.0, 1.0, 1.0, 1.0].into(),
+                    },
+                )
+                // Add the cell boundary to the mesh builder
+                .rectangle(
+                    graphics::DrawMode::Stroke(graphics::StrokeOptions::default()),
+                    cellbounds,
+                    [1.0, 1.0, 1.0, 0.25].into(),
+                );
+            }
+        }
+    }
+}
+
+// A function that returns the range of cell indices along an axis that lie within the visible
+// span starting at the given position with the given length, clamped to the number of cells.
+pub(crate) fn visible_range(start: f32, length: f32, cellsize: f32, cells: usize) -> (usize, usize) {
+    let first = (start / cellsize).floor().max(0.0) as usize;
+    let last = ((start + length) / cellsize).ceil().max(0.0) as usize;
+    (first.min(cells), last.min(cells))
+}
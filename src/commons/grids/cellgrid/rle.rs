@@ -0,0 +1,298 @@
+use crate::{Error, Result};
+use crate::commons::grids::cellgrid::Pattern;
+
+/// Represents the maximum length of a line of an RLE pattern body.
+const RLE_LINE_LENGTH: usize = 70;
+
+/// Implementation of the Run Length Encoded (.rle) format for Pattern.
+///
+/// An RLE pattern has optional ``#`` comment lines (``#N`` name, ``#C`` or ``#c`` comment,
+/// ``#O`` author, ``#P`` or ``#R`` position, ``#r`` rule), a header line ``x = {width}, y = {height}, rule = {rule}``
+/// and a body of runs of dead (``b``) and alive (``o``) cells, with rows
+/// ending in ``$`` and the pattern ending in ``!``.
+impl Pattern {
+    /// A function that parses a pattern in the RLE format.
+    /// Returns an error if the live cells do not fit within the size given by the header.
+    pub fn from_rle(text: &str) -> Result<Self> {
+        let mut pattern = Pattern::default();
+        // Declare the size given by the header, if any
+        let mut header: Option<(usize, usize)> = None;
+
+        // Declare the position and run count of the body parser
+        let (mut x, mut y) = (0, 0);
+        let mut count = String::new();
+        let mut ended = false;
+
+        for line in text.lines().map(str::trim) {
+            // Skip empty lines and anything after the end of the body
+            if line.is_empty() || ended {
+                continue;
+            }
+
+            // Parse the comment lines
+            if let Some(comment) = line.strip_prefix('#') {
+                // Split the kind of the line (the first character) from its value
+                let mut chars = comment.chars();
+                let kind = chars.next();
+                let value = chars.as_str().trim().to_string();
+                match kind {
+                    Some('N') => pattern.name = Some(value),
+                    Some('r') => pattern.rule = Some(value),
+                    // Comments, authors and positions are kept as comments
+                    Some('C') | Some('c') | Some('O') | Some('P') | Some('R') | None => pattern.comments.push(value),
+                    Some(kind) => return Err(invalid(&format!("unsupported comment line '#{}'", kind))),
+                }
+                continue;
+            }
+
+            // Parse the header line
+            if header.is_none() && line.starts_with('x') {
+                header = Some(parse_header(line, &mut pattern)?);
+                continue;
+            }
+
+            // Parse the runs of the body
+            for c in line.chars() {
+                match c {
+                    // Accumulate the run count
+                    '0'..='9' => count.push(c),
+                    // Skip whitespace between runs
+                    c if c.is_whitespace() => {},
+                    // End of the pattern
+                    '!' => {
+                        ended = true;
+                        break;
+                    },
+                    // Run of dead cells, alive cells or ends of rows
+                    'b' | '.' | 'o' | 'A' | '$' => {
+                        let run = match count.is_empty() {
+                            true => 1,
+                            false => count.parse::<usize>().map_err(|_| invalid(&format!("invalid run count '{}'", count)))?,
+                        };
+                        count.clear();
+
+                        match c {
+                            'b' | '.' => x += run,
+                            '$' => {
+                                y += run;
+                                x = 0;
+                            },
+                            _ => {
+                                pattern.cells.extend((x..x + run).map(|cx| (cx, y)));
+                                x += run;
+                            },
+                        }
+                    },
+                    // Any other character is invalid
+                    _ => return Err(invalid(&format!("unexpected character '{}'", c))),
+                }
+            }
+        }
+
+        // Size the pattern from its live cells and check them against the header, if any
+        pattern.width = pattern.cells.iter().map(|&(x, _)| x + 1).max().unwrap_or(0);
+        pattern.height = pattern.cells.iter().map(|&(_, y)| y + 1).max().unwrap_or(0);
+        if let Some((width, height)) = header {
+            if pattern.width > width || pattern.height > height {
+                return Err(invalid(&format!(
+                    "the cells span {}x{}, beyond the {}x{} of the header", pattern.width, pattern.height, width, height
+                )));
+            }
+            // Trailing dead cells are not written, so the header may be larger than the cells
+            pattern.width = width;
+            pattern.height = height;
+        }
+
+        Ok(pattern)
+    }
+
+    /// A method that writes the pattern in the RLE format.
+    pub fn to_rle(&self) -> String {
+        let mut text = String::new();
+
+        // Write the comment lines and the header
+        if let Some(name) = &self.name {
+            text.push_str(&format!("#N {}\n", name));
+        }
+        for comment in &self.comments {
+            text.push_str(&format!("#C {}\n", comment));
+        }
+        text.push_str(&format!("x = {}, y = {}", self.width, self.height));
+        if let Some(rule) = &self.rule {
+            text.push_str(&format!(", rule = {}", rule));
+        }
+        text.push('\n');
+
+        // Collect the runs of the body as tokens (empty rows and trailing dead cells are not written)
+        let mut tokens = Vec::new();
+        // Declare the row that the body has reached
+        let mut row = 0;
+        for (y, xs) in self.rows() {
+            // Write the row ends before the first run of the row
+            if y > row {
+                tokens.push(token(y - row, '$'));
+                row = y;
+            }
+
+            // Collect the runs of the row from its live cells
+            let mut runs: Vec<(usize, char)> = Vec::new();
+            let mut next = 0;
+            for x in xs {
+                // Add the run of dead cells before the cell and extend the run of alive cells
+                if x > next {
+                    runs.push((x - next, 'b'));
+                }
+                match runs.last_mut() {
+                    Some((run, 'o')) => *run += 1,
+                    _ => runs.push((1, 'o')),
+                }
+                next = x + 1;
+            }
+            tokens.extend(runs.into_iter().map(|(run, tag)| token(run, tag)));
+        }
+        tokens.push("!".to_string());
+
+        // Write the tokens in lines of limited length
+        let mut line = String::new();
+        for token in tokens {
+            if line.len() + token.len() > RLE_LINE_LENGTH {
+                text.push_str(&line);
+                text.push('\n');
+                line.clear();
+            }
+            line.push_str(&token);
+        }
+        text.push_str(&line);
+        text.push('\n');
+
+        text
+    }
+}
+
+// A function that parses the header line of an RLE pattern into the size of the pattern.
+// Sets the rule of the pattern if the header has one.
+fn parse_header(line: &str, pattern: &mut Pattern) -> Result<(usize, usize)> {
+    let (mut width, mut height) = (None, None);
+
+    for field in line.split(',') {
+        // Split the field into its key and value
+        let mut parts = field.splitn(2, '=').map(str::trim);
+        let (key, value) = (parts.next().unwrap_or(""), parts.next().unwrap_or(""));
+
+        match key {
+            "x" => width = value.parse::<usize>().ok(),
+            "y" => height = value.parse::<usize>().ok(),
+            "rule" => pattern.rule = Some(value.to_string()),
+            _ => {},
+        }
+    }
+
+    match (width, height) {
+        (Some(width), Some(height)) => Ok((width, height)),
+        _ => Err(invalid(&format!("invalid header '{}'", line))),
+    }
+}
+
+// A function that formats a run of the given length and tag.
+fn token(run: usize, tag: char) -> String {
+    match run {
+        1 => tag.to_string(),
+        _ => format!("{}{}", run, tag),
+    }
+}
+
+// A function that creates an error for an invalid RLE pattern.
+fn invalid(reason: &str) -> Error {
+    Error::InvalidPattern(format!("invalid rle pattern. {}", reason))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commons::grids::Pattern;
+
+    // A function that parses the given RLE pattern and returns its size and sorted live cells
+    fn parse(text: &str) -> (usize, usize, Vec<(usize, usize)>) {
+        let mut pattern = Pattern::from_rle(text).unwrap();
+        pattern.cells.sort_unstable_by_key(|&(x, y)| (y, x));
+        (pattern.width, pattern.height, pattern.cells)
+    }
+
+    #[test]
+    fn builtin_round_trip() {
+        // Check that every built-in pattern is parsed back from its RLE form
+        for name in Pattern::builtin_names() {
+            let pattern = Pattern::builtin(name).unwrap();
+            let parsed = Pattern::from_rle(&pattern.to_rle()).unwrap();
+            assert_eq!(parsed, pattern, "pattern {}", name);
+        }
+    }
+
+    #[test]
+    fn long_round_trip() {
+        // Create a pattern with rows of alternating cells, empty rows and a trailing empty row
+        let cells: Vec<(usize, usize)> = (0..3).flat_map(|y| (0..100).step_by(2).map(move |x| (x, y * 3))).collect();
+        let mut pattern = Pattern::new(cells);
+        pattern.height += 1;
+        pattern.name = Some("Stripes".to_string());
+        pattern.comments = vec!["first".to_string(), "second".to_string()];
+
+        // Check that the body is split into lines of limited length and parsed back
+        let text = pattern.to_rle();
+        assert!(text.lines().count() > 5, "{}", text);
+        assert!(text.lines().all(|line| line.len() <= 70), "{}", text);
+        assert!(text.contains("3$"), "{}", text);
+        assert_eq!(Pattern::from_rle(&text).unwrap(), pattern);
+    }
+
+    #[test]
+    fn parse_body() {
+        let glider = (3, 3, vec![(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]);
+
+        // Runs split across lines (including a run count) and whitespace between runs
+        assert_eq!(parse("x = 3, y = 3\nbo$2b\no$\n3o!"), glider);
+        assert_eq!(parse("x = 3, y = 3\nb o $ 2b o $\n\n 3o !"), glider);
+        assert_eq!(parse("x = 12, y = 1\n1\n2o!"), (12, 1, (0..12).map(|x| (x, 0)).collect()));
+
+        // Row ends with run counts skip empty rows
+        assert_eq!(parse("x = 2, y = 5\no3$bo$o!"), (2, 5, vec![(0, 0), (1, 3), (0, 4)]));
+        assert_eq!(parse("x = 1, y = 3\n$$o!"), (1, 3, vec![(0, 2)]));
+
+        // Anything after the end of the pattern is skipped
+        assert_eq!(parse("x = 1, y = 1\no!3o\n$5o"), (1, 1, vec![(0, 0)]));
+
+        // The alternative tags of dead and alive cells are accepted
+        assert_eq!(parse("x = 3, y = 1\nA.A!"), (3, 1, vec![(0, 0), (2, 0)]));
+
+        // Without a header, the pattern is sized from its cells
+        assert_eq!(parse("bo$2bo$3o!"), glider);
+    }
+
+    #[test]
+    fn parse_comments() {
+        let text = "#N Glider\n#C A spaceship\n#c lowercase\n#O Someone\n#P 0 0\n#r B3/S23\nx = 3, y = 3, rule = B36/S23\nbo$2bo$3o!";
+        let pattern = Pattern::from_rle(text).unwrap();
+
+        // Check that the name is kept, the rule of the header wins and the rest are kept as comments
+        assert_eq!(pattern.name.as_deref(), Some("Glider"));
+        assert_eq!(pattern.rule.as_deref(), Some("B36/S23"));
+        assert_eq!(pattern.comments, vec!["A spaceship", "lowercase", "Someone", "0 0"]);
+    }
+
+    #[test]
+    fn reject_invalid() {
+        for text in [
+            // Unsupported comment lines
+            "#X extra\nx = 1, y = 1\no!",
+            "#Life 1.06\n0 0",
+            // Invalid headers and characters
+            "x = 1\no!",
+            "x = a, y = 1\no!",
+            "x = 3, y = 3\nbo$2bz$3o!",
+            // Live cells beyond the size of the header
+            "x = 2, y = 3\nbo$2bo$3o!",
+            "x = 3, y = 2\nbo$2bo$3o!",
+        ] {
+            assert!(Pattern::from_rle(text).is_err(), "{}", text);
+        }
+    }
+}
@@ -1,9 +1,30 @@
 pub mod cellgrid;
+pub mod bitgrid;
+pub mod sparsegrid;
+pub mod quadtree;
 pub mod scanner;
+pub mod boundary;
+pub mod neighbourhood;
 
-pub use cellgrid::CellGrid;
+pub use cellgrid::{CellGrid, Pattern, Placement};
+pub use bitgrid::BitGrid;
+pub use sparsegrid::SparseGrid;
+pub use quadtree::QuadTree;
+pub use boundary::Boundary;
 pub use scanner::GridScanner;
+pub use neighbourhood::{Neighbourhood, Neighbours};
 
 /// A type alias for a cell on the grid and its position in that grid
 /// Represents the x, y position and the cell at that position on the grid
 pub type GridCell<C> = (usize, usize, C);
+
+/// A type alias for a cell and its signed position on a grid.
+/// Positions may lie beyond the edges of a grid (ex: left of or above the origin of an unbounded grid)
+pub type GridPoint<C> = (isize, isize, C);
+
+/// A function that converts the given signed position into a position on a bounded grid.
+/// Returns None if the position lies left of or above the origin of the grid.
+pub fn unsigned(x: isize, y: isize) -> Option<(usize, usize)> {
+    use std::convert::TryFrom;
+    Some((usize::try_from(x).ok()?, usize::try_from(y).ok()?))
+}
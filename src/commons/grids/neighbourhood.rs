@@ -0,0 +1,227 @@
+use std::fmt;
+
+use crate::simulation::SimCell;
+use crate::commons::grids::{CellGrid, GridCell};
+
+/// A struct that represents the neighbourhood of a cell on a grid as
+/// the offsets of its neighbours (in cells) from the position of the cell.
+///
+/// Neighbourhoods are built once and reused for every cell of the grid.
+/// The neighbours of a cell are iterated with ``CellGrid::neighbours``, which
+/// resolves each offset position with the boundary policy of the grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Neighbourhood {
+    // The name of the neighbourhood
+    name: String,
+    // The offsets of the neighbours from the cell
+    offsets: Vec<(isize, isize)>,
+}
+
+/// Implementation of constructors and methods for Neighbourhood
+impl Neighbourhood {
+    /// A constructor function that creates the Moore neighbourhood.
+    /// The 8 cells that surround the cell (orthogonally and diagonally).
+    pub fn moore() -> Self {
+        Self::with_name("moore", offsets(1, |_, _| true))
+    }
+
+    /// A constructor function that creates the von Neumann neighbourhood.
+    /// The 4 cells that are orthogonally adjacent to the cell.
+    pub fn vonneumann() -> Self {
+        Self::with_name("vonneumann", offsets(1, |dx, dy| dx == 0 || dy == 0))
+    }
+
+    /// A constructor function that creates the hexagonal neighbourhood.
+    /// The hexagonal grid is emulated on the square grid by skewing its rows, so a cell
+    /// has the 6 neighbours of the Moore neighbourhood without its NE and SW corners.
+    pub fn hexagonal() -> Self {
+        Self::with_name("hexagonal", offsets(1, |dx, dy| (dx, dy) != (1, -1) && (dx, dy) != (-1, 1)))
+    }
+
+    /// A constructor function that creates the extended Moore neighbourhood of the given radius.
+    /// The (2r+1)² - 1 cells that lie within r cells of the cell on both axes.
+    /// A radius of 1 is the Moore neighbourhood and a radius of 0 has no neighbours.
+    pub fn extended(radius: usize) -> Self {
+        Self::with_name(&format!("extended-{}", radius), offsets(radius as isize, |_, _| true))
+    }
+
+    /// A constructor function that creates a custom neighbourhood from the given offsets.
+    /// The offsets are kept in the given order and may include the cell itself (0, 0).
+    pub fn custom(offsets: Vec<(isize, isize)>) -> Self {
+        Self::with_name("custom", offsets)
+    }
+
+    // A constructor function that creates a neighbourhood with the given name and offsets.
+    fn with_name(name: &str, offsets: Vec<(isize, isize)>) -> Self {
+        Self { name: name.to_string(), offsets }
+    }
+
+    /// A getter method that returns the offsets of the neighbours from the cell.
+    pub fn offsets(&self) -> &[(isize, isize)] {
+        &self.offsets
+    }
+
+    /// A getter method that returns the number of neighbours of a cell.
+    /// Cells near a dead edge of a grid may have fewer neighbours on that grid.
+    pub fn len(&self) -> usize {
+        self.offsets.len()
+    }
+
+    /// A method that returns whether the neighbourhood has no neighbours.
+    pub fn is_empty(&self) -> bool {
+        self.offsets.is_empty()
+    }
+
+    /// A method that returns the radius of the neighbourhood i.e the
+    /// largest distance of a neighbour from the cell on either axis.
+    pub fn radius(&self) -> usize {
+        self.offsets.iter()
+            .map(|(dx, dy)| dx.unsigned_abs().max(dy.unsigned_abs()))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// Implementation of the Default trait for Neighbourhood (the Moore neighbourhood)
+impl Default for Neighbourhood {
+    fn default() -> Self {
+        Self::moore()
+    }
+}
+
+/// Implementation of the Display trait for Neighbourhood
+impl fmt::Display for Neighbourhood {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// A struct that represents an iterator over the neighbours of a cell on a CellGrid.
+/// The offset positions of the neighbourhood are resolved with the boundary policy of the
+/// grid and each neighbour is yielded with its position. Positions beyond a dead edge are
+/// skipped, while toroidal and reflective edges may yield the same cell more than once.
+pub struct Neighbours<'a, T> where T: SimCell {
+    // The grid of the cell
+    grid: &'a CellGrid<T>,
+    // The position of the cell
+    x: isize,
+    y: isize,
+    // The iterator over the remaining offsets of the neighbourhood
+    offsets: std::slice::Iter<'a, (isize, isize)>,
+}
+
+/// Implementation of Neighbours constructor
+impl<'a, T: SimCell> Neighbours<'a, T> {
+    /// A constructor function that creates an iterator over the neighbours of
+    /// the cell at the given position of the grid in the given neighbourhood.
+    pub fn new(grid: &'a CellGrid<T>, x: usize, y: usize, neighbourhood: &'a Neighbourhood) -> Self {
+        Self { grid, x: x as isize, y: y as isize, offsets: neighbourhood.offsets().iter() }
+    }
+}
+
+/// Implementation of the Iterator trait for Neighbours
+impl<'a, T: SimCell> Iterator for Neighbours<'a, T> {
+    /// Define the iterator item type
+    type Item = GridCell<&'a T>;
+
+    /// A method that advances the iterator to the next neighbour of the cell
+    fn next(&mut self) -> Option<Self::Item> {
+        // Iterate over the remaining offsets until one resolves onto the grid
+        for (dx, dy) in self.offsets.by_ref() {
+            if let Some((nx, ny)) = self.grid.resolve(self.x + dx, self.y + dy) {
+                return Some((nx, ny, &self.grid[(nx, ny)]));
+            }
+        }
+
+        None
+    }
+
+    /// A method that returns the bounds on the number of remaining neighbours
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.offsets.len()))
+    }
+}
+
+// A function that collects the offsets within the given radius of a cell (except the
+// cell itself) that are accepted by the given function, in row-major order.
+fn offsets<F: Fn(isize, isize) -> bool>(radius: isize, accept: F) -> Vec<(isize, isize)> {
+    (-radius..=radius)
+        .flat_map(|dy| (-radius..=radius).map(move |dx| (dx, dy)))
+        .filter(|&(dx, dy)| (dx, dy) != (0, 0) && accept(dx, dy))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commons::cells::BinaryCell;
+    use crate::commons::grids::{Boundary, CellGrid, Neighbourhood};
+
+    // A function that returns the positions of the neighbours of the given cell of
+    // a 3x3 grid with the given boundary in the given neighbourhood (in order)
+    fn neighbours(boundary: Boundary, neighbourhood: &Neighbourhood, x: usize, y: usize) -> Vec<(usize, usize)> {
+        let mut grid = CellGrid::from_fn(3, 3, |_, _| BinaryCell::Passive);
+        grid.boundary = boundary;
+        grid.neighbours(x, y, neighbourhood).map(|(nx, ny, _)| (nx, ny)).collect()
+    }
+
+    #[test]
+    fn offsets() {
+        // Check the number of neighbours and the radius of each neighbourhood
+        let table = [
+            (Neighbourhood::moore(), 8, 1),
+            (Neighbourhood::vonneumann(), 4, 1),
+            (Neighbourhood::hexagonal(), 6, 1),
+            (Neighbourhood::extended(1), 8, 1),
+            (Neighbourhood::extended(2), 24, 2),
+            (Neighbourhood::extended(0), 0, 0),
+            (Neighbourhood::custom(vec![(0, 0)]), 1, 0),
+            (Neighbourhood::custom(vec![(2, -5), (-1, 0)]), 2, 5),
+        ];
+        for (neighbourhood, len, radius) in table.iter() {
+            assert_eq!(neighbourhood.len(), *len, "{}", neighbourhood);
+            assert_eq!(neighbourhood.radius(), *radius, "{}", neighbourhood);
+            assert_eq!(neighbourhood.is_empty(), *len == 0, "{}", neighbourhood);
+        }
+
+        // Check the shapes of the neighbourhoods with a radius of 1
+        assert_eq!(Neighbourhood::vonneumann().offsets(), &[(0, -1), (-1, 0), (1, 0), (0, 1)]);
+        assert_eq!(Neighbourhood::hexagonal().offsets(), &[(-1, -1), (0, -1), (-1, 0), (1, 0), (0, 1), (1, 1)]);
+        assert_eq!(Neighbourhood::extended(1).offsets(), Neighbourhood::moore().offsets());
+        assert_eq!(Neighbourhood::default(), Neighbourhood::moore());
+    }
+
+    #[test]
+    fn neighbours_of_corner() {
+        let moore = Neighbourhood::moore();
+
+        // Positions beyond dead edges are skipped
+        assert_eq!(neighbours(Boundary::Dead, &moore, 0, 0), vec![(1, 0), (0, 1), (1, 1)]);
+        // Positions beyond toroidal edges wrap around to the opposite edges
+        assert_eq!(neighbours(Boundary::Toroidal, &moore, 0, 0), vec![(2, 2), (0, 2), (1, 2), (2, 0), (1, 0), (2, 1), (0, 1), (1, 1)]);
+        // Positions beyond reflective edges are mirrored back, so the cell itself and
+        // the cells along the edges are yielded more than once
+        assert_eq!(neighbours(Boundary::Reflective, &moore, 0, 0), vec![(0, 0), (0, 0), (1, 0), (0, 0), (1, 0), (0, 1), (0, 1), (1, 1)]);
+    }
+
+    #[test]
+    fn neighbours_of_centre() {
+        // Every boundary yields the 8 surrounding cells of the centre cell
+        let moore = Neighbourhood::moore();
+        let expected = vec![(0, 0), (1, 0), (2, 0), (0, 1), (2, 1), (0, 2), (1, 2), (2, 2)];
+        for &boundary in &[Boundary::Dead, Boundary::Toroidal, Boundary::Reflective] {
+            assert_eq!(neighbours(boundary, &moore, 1, 1), expected, "{}", boundary);
+        }
+
+        // A toroidal grid smaller than the neighbourhood yields each cell once for every offset
+        // that wraps onto it (the corners 4 times, the edges twice and the cell itself never)
+        let found = neighbours(Boundary::Toroidal, &Neighbourhood::extended(2), 1, 1);
+        let count = |x, y| found.iter().filter(|&&position| position == (x, y)).count();
+        assert_eq!(found.len(), 24);
+        assert_eq!([count(0, 0), count(2, 0), count(0, 2), count(2, 2)], [4; 4]);
+        assert_eq!([count(1, 0), count(0, 1), count(2, 1), count(1, 2)], [2; 4]);
+        assert_eq!(count(1, 1), 0);
+
+        // A dead grid skips the positions beyond its edges
+        assert_eq!(neighbours(Boundary::Dead, &Neighbourhood::extended(2), 1, 1), expected);
+    }
+}
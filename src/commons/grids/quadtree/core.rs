@@ -0,0 +1,470 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use rand::Rng;
+use serde::{Serialize, Deserialize};
+
+use crate::{Error, Result};
+use crate::commons::cells::BinaryCell;
+use crate::commons::navigation::Direction4;
+use crate::simulation::SimGrid;
+use crate::commons::grids::{Boundary, CellGrid, GridPoint};
+use crate::commons::grids::quadtree::{Node, Store};
+
+/// Represents the smallest level of the root of a quadtree (8x8 cells).
+const MIN_LEVEL: u8 = 3;
+/// Represents the largest level of the root of a quadtree (coordinates must fit in an i64).
+const MAX_LEVEL: u8 = 62;
+
+/// A struct that represents an unbounded grid of binary cells stored as a quadtree.
+/// Clones of a grid share the store of its nodes. Snapshots hold the live cells of the grid.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(into = "QuadTreeState", from = "QuadTreeState")]
+pub struct QuadTree {
+    /// Represents the store that creates the nodes of the tree
+    store: Rc<RefCell<Store>>,
+    /// Represents the root node of the tree (centred on the origin)
+    root: Rc<Node>,
+    /// Represents the width of the frame of the grid (in cells)
+    pub width: usize,
+    /// Represents the height of the frame of the grid (in cells)
+    pub height: usize,
+}
+
+/// A struct that represents the serialized state of a QuadTree.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct QuadTreeState {
+    /// Represents the positions of the live cells
+    cells: Vec<(i64, i64)>,
+    /// Represents the width of the frame of the grid (in cells)
+    width: usize,
+    /// Represents the height of the frame of the grid (in cells)
+    height: usize,
+}
+
+/// Implementation of the SimGrid trait for QuadTree
+impl SimGrid for QuadTree {
+    /// Define the type of cell in the grid
+    type Cell = BinaryCell;
+
+    /// Define the type of compass
+    type Orientation = Direction4;
+
+    /// A constructor method that creates a null grid.
+    fn new() -> Self {
+        let mut store = Store::new();
+        let root = store.empty(MIN_LEVEL);
+
+        Self {
+            store: Rc::new(RefCell::new(store)),
+            root,
+            width: 0,
+            height: 0,
+        }
+    }
+
+    /// A getter method that returns the name of the type of grid.
+    /// Ex: "HashLife"
+    fn name(&self) -> String {
+        "HashLife".to_string()
+    }
+
+    /// A setter method that sets the live cells of the given grid
+    /// to the struct. The frame is set to the dimensions of the grid.
+    fn setgrid(&mut self, other: CellGrid<Self::Cell>) {
+        self.width = other.getwidth();
+        self.height = other.getheight();
+        self.root = self.store.borrow_mut().empty(MIN_LEVEL);
+
+        for (x, y, &cell) in other.iter() {
+            if cell == BinaryCell::Active {
+                self.set(x as i64, y as i64, cell);
+            }
+        }
+    }
+
+    /// A setter method that sets the cell at the given position.
+    fn setcell(&mut self, x: isize, y: isize, cell: Self::Cell) -> bool {
+        self.set(x as i64, y as i64, cell);
+        true
+    }
+
+    /// A getter method that returns the height of the frame of the grid (number of rows).
+    fn getheight(&self) -> usize {
+        self.height
+    }
+
+    /// A getter method that returns the width of the frame of the grid (number of columns).
+    fn getwidth(&self) -> usize {
+        self.width
+    }
+
+    /// A getter method that returns the cell at the given position.
+    fn getcell(&self, x: isize, y: isize) -> Option<Self::Cell> {
+        Some(self.get(x as i64, y as i64))
+    }
+
+    /// A getter method that returns the boundary policy of the grid.
+    fn boundary(&self) -> Boundary {
+        Boundary::Dead
+    }
+
+    /// A setter method that sets the boundary policy of the grid.
+    fn setboundary(&mut self, _boundary: Boundary) {}
+
+    /// A method that returns a random cell from the frame of the grid.
+    /// Returns the x,y position of the cell along with cell state as
+    /// a GridPoint or an error if the frame of the grid has no cells.
+    fn randomcell<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<GridPoint<Self::Cell>> {
+        // Check if the frame has any cells
+        if self.width == 0 || self.height == 0 {
+            return Err(Error::EmptyGrid);
+        }
+
+        // Get a random column and row from the frame
+        let col = rng.gen_range(0..self.width) as isize;
+        let row = rng.gen_range(0..self.height) as isize;
+        // Build the GridPoint and return it
+        Ok((col, row, self.get(col as i64, row as i64)))
+    }
+
+    /// A method that returns the state in the given previous grid
+    /// of every cell that differs between it and the current grid.
+    /// Squares of cells that are shared by both trees are skipped.
+    fn changes(&self, previous: &Self) -> Vec<GridPoint<Self::Cell>> {
+        // Bring both roots to the same level
+        let (mut current, mut old) = (self.root.clone(), previous.root.clone());
+        {
+            let mut store = self.store.borrow_mut();
+            while current.level < old.level {
+                current = store.expand(&current);
+            }
+            while old.level < current.level {
+                old = store.expand(&old);
+            }
+        }
+
+        // Collect the cells that differ between the trees
+        let mut changes = Vec::new();
+        let half = 1i64 << (current.level - 1);
+        difference(&current, &old, -half, -half, &mut changes);
+        changes
+    }
+}
+
+/// Implementation of helper methods for QuadTree.
+impl QuadTree {
+    /// A getter method that returns the store that creates the nodes of the tree.
+    pub fn store(&self) -> &Rc<RefCell<Store>> {
+        &self.store
+    }
+
+    /// A getter method that returns the root node of the tree.
+    pub fn root(&self) -> &Rc<Node> {
+        &self.root
+    }
+
+    /// A setter method that sets the root node of the tree. The root must have been created
+    /// by the store of the tree and is centred on the origin. Empty borders are removed.
+    pub fn setroot(&mut self, root: Rc<Node>) {
+        self.root = self.shrink(&root);
+    }
+
+    /// A method that returns the given root surrounded by empty borders until it is at least
+    /// at the given level and all of its live cells are within its centre quarter.
+    pub fn pad(&self, root: &Rc<Node>, level: u8) -> Rc<Node> {
+        let mut store = self.store.borrow_mut();
+        let mut root = root.clone();
+        while root.level < MAX_LEVEL && (root.level < level || !centred(&root, 2)) {
+            root = store.expand(&root);
+        }
+
+        root
+    }
+
+    /// A method that returns the given root with its empty borders removed
+    /// for as long as all of its live cells are within its centre half.
+    pub fn shrink(&self, root: &Rc<Node>) -> Rc<Node> {
+        let mut store = self.store.borrow_mut();
+        let mut root = root.clone();
+        while root.level > MIN_LEVEL && centred(&root, 1) {
+            root = store.centre(&root);
+        }
+
+        root
+    }
+
+    /// A getter method that returns the cell at the given coordinates.
+    pub fn get(&self, x: i64, y: i64) -> BinaryCell {
+        // Check if the coordinates are beyond the root
+        let half = 1i64 << (self.root.level - 1);
+        if x < -half || y < -half || x >= half || y >= half {
+            return BinaryCell::Passive;
+        }
+
+        // Descend into the quadrants that hold the coordinates
+        let (mut x, mut y) = ((x + half) as u64, (y + half) as u64);
+        let mut node = &self.root;
+        while let Some(quadrants) = &node.children {
+            if node.population == 0 {
+                break;
+            }
+
+            let half = 1u64 << (node.level - 1);
+            node = &quadrants[quadrant(x >= half, y >= half)];
+            x %= half;
+            y %= half;
+        }
+
+        match node.population {
+            0 => BinaryCell::Passive,
+            _ => BinaryCell::Active,
+        }
+    }
+
+    /// A setter method that sets the cell at the given coordinates.
+    /// The root grows until it holds the coordinates.
+    pub fn set(&mut self, x: i64, y: i64, cell: BinaryCell) {
+        let mut store = self.store.borrow_mut();
+
+        // Grow the root until it holds the coordinates
+        while self.root.level < MAX_LEVEL {
+            let half = 1i64 << (self.root.level - 1);
+            if x >= -half && y >= -half && x < half && y < half {
+                break;
+            }
+            self.root = store.expand(&self.root);
+        }
+
+        // Rebuild the path from the root to the cell
+        let half = 1i64 << (self.root.level - 1);
+        self.root = setnode(&mut store, &self.root, (x + half) as u64, (y + half) as u64, cell == BinaryCell::Active);
+    }
+
+    /// A method that resizes the frame of the grid to the given dimensions (in cells).
+    pub fn resize(&mut self, width: usize, height: usize) {
+        self.width = width;
+        self.height = height;
+    }
+
+    /// A method that returns whether the given coordinates are within the frame of the grid.
+    pub fn inframe(&self, x: i64, y: i64) -> bool {
+        x >= 0 && y >= 0 && x < self.width as i64 && y < self.height as i64
+    }
+
+    /// A method that returns the positions of the live cells of the grid.
+    pub fn cells(&self) -> Vec<(i64, i64)> {
+        let mut cells = Vec::new();
+        let half = 1i64 << (self.root.level - 1);
+        collect(&self.root, -half, -half, &mut cells);
+        cells
+    }
+
+    /// A method that returns the number of live cells within the given
+    /// region (from the top left corner up to the bottom right corner, exclusive).
+    pub fn count(&self, left: i64, top: i64, right: i64, bottom: i64) -> u64 {
+        let half = 1i64 << (self.root.level - 1);
+        count(&self.root, -half, -half, (left, top, right, bottom))
+    }
+
+    /// A method that returns the bounding box of the live cells as the coordinates
+    /// of its top left and bottom right cells. Returns None if no cells are alive.
+    pub fn bounds(&self) -> Option<((i64, i64), (i64, i64))> {
+        let half = 1i64 << (self.root.level - 1);
+        let left = edge(&self.root, -half, true, true)?;
+        let right = edge(&self.root, -half, true, false)?;
+        let top = edge(&self.root, -half, false, true)?;
+        let bottom = edge(&self.root, -half, false, false)?;
+        Some(((left, top), (right, bottom)))
+    }
+
+    /// A method that counts the cells of the grid by state. Returns the number of
+    /// live cells (anywhere) and the number of dead cells within the frame as a tuple.
+    pub fn population(&self) -> (u32, u32) {
+        let inframe = self.count(0, 0, self.width as i64, self.height as i64);
+        let passive = ((self.width * self.height) as u64).saturating_sub(inframe);
+        (self.root.population.min(u32::MAX as u64) as u32, passive.min(u32::MAX as u64) as u32)
+    }
+
+    /// A method that rebuilds the tree in a new store. The memoized
+    /// results of the nodes are dropped (ex: when the rule changes).
+    pub fn rebuild(&mut self) {
+        let cells = self.cells();
+        let (width, height) = (self.width, self.height);
+
+        *self = Self::new();
+        self.width = width;
+        self.height = height;
+        for (x, y) in cells {
+            self.set(x, y, BinaryCell::Active);
+        }
+    }
+}
+
+/// Implementation of the IntoIterator trait for QuadTree.
+/// Only the live cells of the grid are iterated over.
+impl IntoIterator for QuadTree {
+    /// Define the iteration item type
+    type Item = GridPoint<BinaryCell>;
+    /// Define the iterator type
+    type IntoIter = std::vec::IntoIter<GridPoint<BinaryCell>>;
+
+    /// A method that creates an iterator over the live cells of the QuadTree.
+    fn into_iter(self) -> Self::IntoIter {
+        self.cells().into_iter()
+            .map(|(x, y)| (x as isize, y as isize, BinaryCell::Active))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+/// Implementation of the conversion of a QuadTree into its serialized state
+impl From<QuadTree> for QuadTreeState {
+    fn from(grid: QuadTree) -> Self {
+        Self { cells: grid.cells(), width: grid.width, height: grid.height }
+    }
+}
+
+/// Implementation of the conversion of a serialized state into a QuadTree
+impl From<QuadTreeState> for QuadTree {
+    fn from(state: QuadTreeState) -> Self {
+        let mut grid = Self::new();
+        grid.width = state.width;
+        grid.height = state.height;
+        for (x, y) in state.cells {
+            grid.set(x, y, BinaryCell::Active);
+        }
+
+        grid
+    }
+}
+
+// A function that returns the index of the quadrant on the given sides of a node.
+fn quadrant(east: bool, south: bool) -> usize {
+    east as usize + 2 * south as usize
+}
+
+// A function that returns whether all the live cells of the given node are within its
+// centre. The depth is 1 for the centre half and 2 for the centre quarter of the node.
+fn centred(node: &Node, depth: u8) -> bool {
+    // Follow each quadrant towards the centre of the node
+    node.quadrants().iter().enumerate().all(|(i, quadrant)| {
+        let mut inner = quadrant;
+        for _ in 0..depth {
+            inner = &inner.quadrants()[3 - i];
+        }
+        inner.population == quadrant.population
+    })
+}
+
+// A function that returns the given node with the cell at the given position
+// (relative to the top left corner of the node) set to the given state.
+fn setnode(store: &mut Store, node: &Node, x: u64, y: u64, alive: bool) -> Rc<Node> {
+    match &node.children {
+        None => store.cell(alive),
+        Some(quadrants) => {
+            let half = 1u64 << (node.level - 1);
+            let i = quadrant(x >= half, y >= half);
+            let mut children = quadrants.clone();
+            children[i] = setnode(store, &quadrants[i], x % half, y % half, alive);
+            store.node(children)
+        }
+    }
+}
+
+// A function that collects the positions of the live cells of the
+// given node with its top left corner at the given coordinates.
+fn collect(node: &Node, left: i64, top: i64, cells: &mut Vec<(i64, i64)>) {
+    match &node.children {
+        _ if node.population == 0 => {},
+        None => cells.push((left, top)),
+        Some(quadrants) => {
+            let half = 1i64 << (node.level - 1);
+            for (i, quadrant) in quadrants.iter().enumerate() {
+                collect(quadrant, left + half * (i % 2) as i64, top + half * (i / 2) as i64, cells);
+            }
+        }
+    }
+}
+
+// A function that counts the live cells of the given node with its top left corner at the given
+// coordinates that are within the given region (left, top, right, bottom with exclusive ends).
+fn count(node: &Node, left: i64, top: i64, region: (i64, i64, i64, i64)) -> u64 {
+    let size = 1i64 << node.level;
+    let (rleft, rtop, rright, rbottom) = region;
+
+    // Check if the node is empty or outside the region
+    if node.population == 0 || left >= rright || top >= rbottom || left + size <= rleft || top + size <= rtop {
+        return 0;
+    }
+    // Check if the node is entirely within the region
+    if left >= rleft && top >= rtop && left + size <= rright && top + size <= rbottom {
+        return node.population;
+    }
+
+    // Otherwise count the cells in each quadrant
+    let half = size / 2;
+    node.quadrants().iter().enumerate()
+        .map(|(i, quadrant)| count(quadrant, left + half * (i % 2) as i64, top + half * (i / 2) as i64, region))
+        .sum()
+}
+
+// A function that returns the coordinate of the outermost live cell of the given node with
+// its corner at the given coordinate along one axis (x if horizontal, otherwise y). The
+// smallest coordinate is returned if least is set, otherwise the largest one.
+fn edge(node: &Node, start: i64, horizontal: bool, least: bool) -> Option<i64> {
+    if node.population == 0 {
+        return None;
+    }
+
+    let quadrants = match &node.children {
+        None => return Some(start),
+        Some(quadrants) => quadrants,
+    };
+
+    // Split the quadrants into the near and far halves along the axis
+    let half = 1i64 << (node.level - 1);
+    let (low, high) = match horizontal {
+        true => ([&quadrants[0], &quadrants[2]], [&quadrants[1], &quadrants[3]]),
+        false => ([&quadrants[0], &quadrants[1]], [&quadrants[2], &quadrants[3]]),
+    };
+    let (near, far, nearstart, farstart) = match least {
+        true => (low, high, start, start + half),
+        false => (high, low, start + half, start),
+    };
+
+    // Search the near half first and only search the far half if the near half is empty
+    let pick = |a: Option<i64>, b: Option<i64>| match (a, b, least) {
+        (Some(a), Some(b), true) => Some(a.min(b)),
+        (Some(a), Some(b), false) => Some(a.max(b)),
+        (a, b, _) => a.or(b),
+    };
+    let found = near.iter().fold(None, |best, quadrant| pick(best, edge(quadrant, nearstart, horizontal, least)));
+    found.or_else(|| far.iter().fold(None, |best, quadrant| pick(best, edge(quadrant, farstart, horizontal, least))))
+}
+
+// A function that collects the previous state of every cell that differs between the given
+// current and old nodes (at the same level) with their top left corner at the given coordinates.
+fn difference(current: &Rc<Node>, old: &Rc<Node>, left: i64, top: i64, changes: &mut Vec<GridPoint<BinaryCell>>) {
+    // Skip squares that are shared by both trees or empty in both
+    if Rc::ptr_eq(current, old) || (current.population == 0 && old.population == 0) {
+        return;
+    }
+
+    match (&current.children, &old.children) {
+        (Some(cquadrants), Some(oquadrants)) => {
+            let half = 1i64 << (current.level - 1);
+            for i in 0..4 {
+                let (x, y) = (left + half * (i % 2) as i64, top + half * (i / 2) as i64);
+                difference(&cquadrants[i], &oquadrants[i], x, y, changes);
+            }
+        },
+        // Single cells of trees from different stores are compared by state
+        _ if current.population != old.population => {
+            let cell = if old.population > 0 { BinaryCell::Active } else { BinaryCell::Passive };
+            changes.push((left as isize, top as isize, cell));
+        },
+        _ => {},
+    }
+}
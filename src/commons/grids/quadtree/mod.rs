@@ -0,0 +1,19 @@
+//! An unbounded grid of binary cells stored as a quadtree, advanced with HashLife.
+//!
+//! Nodes are immutable and shared. The store hands out a single node for every distinct
+//! square of cells, so equal squares are the same node and can be compared by pointer,
+//! and large and repetitive patterns stay small. The future of every square of cells is
+//! memoized on its node, so repetitive patterns can be advanced by 2^k generations in a
+//! single step.
+//!
+//! The root of the tree is centred on the origin and grows as cells are set further away.
+//! Positions and the frame of the grid work as they do for the SparseGrid.
+
+pub mod node;
+pub mod core;
+pub mod pattern;
+#[cfg(feature = "graphics")]
+pub mod render;
+
+pub use crate::commons::grids::quadtree::node::{Node, Store};
+pub use crate::commons::grids::quadtree::core::QuadTree;
@@ -0,0 +1,183 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Represents the number of nodes in the store beyond which the memoized results are dropped.
+const TRIM_THRESHOLD: usize = 1 << 22;
+
+/// A struct that represents a node of a quadtree of binary cells.
+/// A node at level n covers a square of 2^n x 2^n cells and a node at level 0 is a single cell.
+pub struct Node {
+    /// Represents the level of the node.
+    pub level: u8,
+    /// Represents the number of live cells covered by the node.
+    pub population: u64,
+    /// Represents the quadrants of the node as [nw, ne, sw, se] (None for a single cell).
+    pub children: Option<[Rc<Node>; 4]>,
+    /// Represents the memoized results of the node, indexed by j. The result is the
+    /// centre of the node (at one level lower) advanced by 2^j generations.
+    pub results: RefCell<Vec<Option<Rc<Node>>>>,
+}
+
+/// Implementation of methods for Node
+impl Node {
+    /// A getter method that returns the quadrants of the node as [nw, ne, sw, se].
+    /// Panics if the node is a single cell, which has no quadrants.
+    pub fn quadrants(&self) -> &[Rc<Node>; 4] {
+        self.children.as_ref().expect("a single cell has no quadrants")
+    }
+
+    /// A getter method that returns the memoized result of the node for the given j, if any.
+    pub fn result(&self, j: u8) -> Option<Rc<Node>> {
+        self.results.borrow().get(j as usize).cloned().flatten()
+    }
+
+    /// A setter method that memoizes the result of the node for the given j.
+    pub fn setresult(&self, j: u8, result: Rc<Node>) {
+        let mut results = self.results.borrow_mut();
+        if results.len() <= j as usize {
+            results.resize(j as usize + 1, None);
+        }
+        results[j as usize] = Some(result);
+    }
+}
+
+/// A struct that represents the store that creates the nodes of quadtrees.
+/// The store keeps every node it creates (so that the memoized results of the
+/// intermediate nodes of a step are kept) until it is trimmed.
+pub struct Store {
+    /// Represents the dead and alive single cells.
+    cells: [Rc<Node>; 2],
+    /// Represents the nodes of the store by the addresses of their quadrants.
+    index: HashMap<[usize; 4], Rc<Node>>,
+    /// Represents the empty nodes of the store, indexed by level.
+    empties: Vec<Rc<Node>>,
+}
+
+/// Implementation of methods for Store
+impl Store {
+    /// A constructor function that creates a store with only the single cells.
+    pub fn new() -> Self {
+        let cell = |population| Rc::new(Node { level: 0, population, children: None, results: RefCell::new(Vec::new()) });
+        let cells = [cell(0), cell(1)];
+
+        Self {
+            empties: vec![cells[0].clone()],
+            cells,
+            index: HashMap::new(),
+        }
+    }
+
+    /// A method that returns the single cell with the given state.
+    pub fn cell(&self, alive: bool) -> Rc<Node> {
+        self.cells[alive as usize].clone()
+    }
+
+    /// A method that returns the node with the given quadrants as [nw, ne, sw, se].
+    /// The quadrants must all be at the same level.
+    pub fn node(&mut self, children: [Rc<Node>; 4]) -> Rc<Node> {
+        // Look up the node by the addresses of its quadrants
+        let key = [0, 1, 2, 3].map(|i| Rc::as_ptr(&children[i]) as usize);
+        if let Some(node) = self.index.get(&key) {
+            return node.clone();
+        }
+
+        // Create the node and add it to the index
+        let node = Rc::new(Node {
+            level: children[0].level + 1,
+            population: children.iter().fold(0u64, |total, child| total.saturating_add(child.population)),
+            children: Some(children),
+            results: RefCell::new(Vec::new()),
+        });
+        self.index.insert(key, node.clone());
+
+        node
+    }
+
+    /// A method that returns the node at the given level with no live cells.
+    pub fn empty(&mut self, level: u8) -> Rc<Node> {
+        while self.empties.len() <= level as usize {
+            let empty = self.empties[self.empties.len() - 1].clone();
+            let node = self.node([empty.clone(), empty.clone(), empty.clone(), empty]);
+            self.empties.push(node);
+        }
+
+        self.empties[level as usize].clone()
+    }
+
+    /// A method that returns the centre of the given node (at one level lower).
+    pub fn centre(&mut self, node: &Node) -> Rc<Node> {
+        let [nw, ne, sw, se] = node.quadrants();
+        self.node([
+            nw.quadrants()[3].clone(), ne.quadrants()[2].clone(),
+            sw.quadrants()[1].clone(), se.quadrants()[0].clone(),
+        ])
+    }
+
+    /// A method that returns the node centred between the given west and east nodes.
+    pub fn horizontal(&mut self, west: &Node, east: &Node) -> Rc<Node> {
+        let ([_, wne, _, wse], [enw, _, esw, _]) = (west.quadrants(), east.quadrants());
+        self.node([wne.clone(), enw.clone(), wse.clone(), esw.clone()])
+    }
+
+    /// A method that returns the node centred between the given north and south nodes.
+    pub fn vertical(&mut self, north: &Node, south: &Node) -> Rc<Node> {
+        let ([_, _, nsw, nse], [snw, sne, _, _]) = (north.quadrants(), south.quadrants());
+        self.node([nsw.clone(), nse.clone(), snw.clone(), sne.clone()])
+    }
+
+    /// A method that returns the given node surrounded by a border of empty cells,
+    /// i.e. a node at one level higher with the given node at its centre.
+    pub fn expand(&mut self, node: &Node) -> Rc<Node> {
+        let [nw, ne, sw, se] = node.quadrants().clone();
+        let empty = self.empty(node.level - 1);
+
+        let nw = self.node([empty.clone(), empty.clone(), empty.clone(), nw]);
+        let ne = self.node([empty.clone(), empty.clone(), ne, empty.clone()]);
+        let sw = self.node([empty.clone(), sw, empty.clone(), empty.clone()]);
+        let se = self.node([se, empty.clone(), empty.clone(), empty]);
+        self.node([nw, ne, sw, se])
+    }
+
+    /// A method that drops the nodes that are no longer used outside of the store once the
+    /// store has grown too large. If the store is still too large, the memoized results are
+    /// dropped as well so that the nodes only held by them are released.
+    pub fn trim(&mut self) {
+        // Check if the store has grown too large
+        if self.index.len() < TRIM_THRESHOLD {
+            return;
+        }
+
+        // Drop the nodes that are no longer used
+        self.collect();
+        if self.index.len() < TRIM_THRESHOLD / 2 {
+            return;
+        }
+
+        // Drop the memoized results of every node and then the nodes that were only held by them
+        for node in self.index.values() {
+            node.results.borrow_mut().clear();
+        }
+        self.collect();
+    }
+
+    // A method that drops the nodes that are only held by the index. Dropping a node
+    // releases its quadrants, so this repeats until no more nodes are dropped.
+    fn collect(&mut self) {
+        loop {
+            let count = self.index.len();
+            self.index.retain(|_, node| Rc::strong_count(node) > 1);
+            if self.index.len() == count {
+                break;
+            }
+        }
+    }
+}
+
+/// Implementation of the Default trait for Store
+impl Default for Store {
+    /// A constructor function that creates a store with only the single cells.
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,39 @@
+use crate::commons::cells::BinaryCell;
+use crate::commons::grids::{GridPoint, Pattern, Placement, QuadTree, SparseGrid};
+
+/// Implementation of methods for Pattern on a QuadTree.
+impl Pattern {
+    /// A constructor function that creates a pattern from the live cells of the given quadtree.
+    /// If bounded is set, the pattern is cropped to the bounding box of the live cells,
+    /// otherwise the pattern spans the frame of the grid along with any live cells beyond it.
+    pub fn from_quadtree(grid: &QuadTree, bounded: bool) -> Self {
+        Self::from_sparse(&grid.to_sparse(), bounded)
+    }
+}
+
+/// Implementation of pattern placement methods for QuadTree.
+impl QuadTree {
+    /// A method that returns the live cells and frame of the grid as a sparse grid.
+    pub fn to_sparse(&self) -> SparseGrid {
+        SparseGrid {
+            cells: self.cells().into_iter().collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// A method that returns the live cells that place the given pattern onto the grid.
+    /// Centred patterns are centred on the frame, but are never clipped to it.
+    pub fn placement(&self, pattern: &Pattern, placement: Placement) -> Vec<GridPoint<BinaryCell>> {
+        // Patterns are placed on the frame in the same way as on a sparse grid
+        let frame = SparseGrid { width: self.width, height: self.height, ..SparseGrid::default() };
+        frame.placement(pattern, placement)
+    }
+
+    /// A method that places the given pattern onto the grid.
+    pub fn place(&mut self, pattern: &Pattern, placement: Placement) {
+        for (x, y, cell) in self.placement(pattern, placement) {
+            self.set(x as i64, y as i64, cell);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use ggez::graphics;
+
+use crate::commons::grids::QuadTree;
+use crate::commons::grids::quadtree::Node;
+
+/// Implementation of rendering methods for QuadTree.
+impl QuadTree {
+    /// A method that adds the live cells of the grid within the visible region (in grid space)
+    /// to the given mesh builder. Live cells are filled white and the frame of the grid is
+    /// outlined with a faint boundary. Dead cells are left to the background.
+    pub fn render(&self, mb: &mut graphics::MeshBuilder, cellsize: f32, visible: graphics::Rect) {
+        // Add the outline of the frame to the mesh builder
+        mb.rectangle(
+            graphics::DrawMode::Stroke(graphics::StrokeOptions::default()),
+            graphics::Rect::new(0.0, 0.0, self.width as f32 * cellsize, self.height as f32 * cellsize),
+            [1.0, 1.0, 1.0, 0.25].into(),
+        );
+
+        // Add the visible live cells of the tree to the mesh builder
+        let half = 1i64 << (self.root().level - 1);
+        rendernode(self.root(), -half, -half, mb, cellsize, visible);
+    }
+
+    /// A method that returns the region (in grid space) covered by the frame
+    /// of the grid and all of its live cells.
+    pub fn extent(&self, cellsize: f32) -> graphics::Rect {
+        // Grow the frame to hold the bounding box of the live cells
+        let ((left, top), (right, bottom)) = match self.bounds() {
+            None => ((0, 0), (self.width as i64 - 1, self.height as i64 - 1)),
+            Some(((left, top), (right, bottom))) => (
+                (left.min(0), top.min(0)),
+                (right.max(self.width as i64 - 1), bottom.max(self.height as i64 - 1)),
+            ),
+        };
+
+        graphics::Rect::new(
+            left as f32 * cellsize,
+            top as f32 * cellsize,
+            (right - left + 1) as f32 * cellsize,
+            (bottom - top + 1) as f32 * cellsize,
+        )
+    }
+}
+
+// A function that adds the visible live cells of the given node with its top
+// left corner at the given coordinates to the mesh builder. Empty and hidden
+// squares of cells are skipped.
+fn rendernode(node: &Node, left: i64, top: i64, mb: &mut graphics::MeshBuilder, cellsize: f32, visible: graphics::Rect) {
+    // Create the bounds of the node
+    let size = (1i64 << node.level) as f32 * cellsize;
+    let bounds = graphics::Rect::new(left as f32 * cellsize, top as f32 * cellsize, size, size);
+
+    // Skip the node if it is empty or hidden
+    if node.population == 0 || !visible.overlaps(&bounds) {
+        return;
+    }
+
+    match &node.children {
+        // Add the cell fill to the mesh builder
+        None => {
+            mb.rectangle(
+                graphics::DrawMode::Fill(graphics::FillOptions::default()),
+                bounds,
+                [1.0, 1.0, 1.0, 1.0].into(),
+            );
+        },
+        // Add the cells of each quadrant
+        Some(quadrants) => {
+            let half = 1i64 << (node.level - 1);
+            for (i, quadrant) in quadrants.iter().enumerate() {
+                rendernode(quadrant, left + half * (i % 2) as i64, top + half * (i / 2) as i64, mb, cellsize, visible);
+            }
+        },
+    }
+}
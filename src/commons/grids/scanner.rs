@@ -1,49 +1,43 @@
-use crate::simulation::{SimCell, SimGrid};
-use crate::commons::grids::CellGrid;
-use crate::commons::grids::GridCell;
+/// A struct that represents a scanning iterator over the cells of a grid.
+/// The cells are scanned in row-major order (left to right, top to bottom) and
+/// each cell is yielded with its position. The cells may be borrowed or owned.
+pub struct GridScanner<I> where I: Iterator {
+    // The iterator over the cells of the grid in row-major order
+    cells: I,
+    // The number of columns of the grid
+    width: usize,
+    // The index of the next cell in the grid
+    index: usize,
+}
 
-/// A struct that represents a scanning iterator for a SimGrid
-pub struct GridScanner<T> where T: SimGrid {
-    // The 2D vector of the grid being scanned
-    pub gridvector: Vec<Vec<T::Cell>>,
-    // The current column position in the Grid
-    pub current_column: usize,
-    // The current row position in the Grid
-    pub current_row: usize,
+/// Implementation of GridScanner constructor
+impl<I: Iterator> GridScanner<I> {
+    /// A constructor function that creates a scanner over the given
+    /// cells (in row-major order) of a grid with the given width.
+    pub fn new(cells: I, width: usize) -> Self {
+        Self { cells, width, index: 0 }
+    }
 }
 
 /// Implementation of the Iterator trait for GridScanner
-impl<C: SimCell> Iterator for GridScanner<CellGrid<C>> {
+impl<I: Iterator> Iterator for GridScanner<I> {
     /// Define the iterator item type
-    type Item = GridCell<C>;
+    type Item = (usize, usize, I::Item);
 
     /// A method that advances the iterator to the next position in the grid
     fn next(&mut self) -> Option<Self::Item> {
-        // Retrieve the element based on the iterator's current position on the grid
-        let element = match (self.current_column, self.current_row) {
-            // If the scanner has reached the end of a row
-            (x, y) if y == self.gridvector[x].len() => {
-                // Rese the row position
-                self.current_row = 0; 
-                // Increment the column position
-                self.current_column += 1;
-                // Check if the column has reached the end of the grid
-                if self.current_column == self.gridvector.len() {
-                    // Return None. End of Iteration
-                    return  None
-                };
-
-                // Build the grid cell element 
-                Some((self.current_column, self.current_row, self.gridvector[self.current_column][self.current_row].clone()))
-            },
+        // Retrieve the next cell (None at the end of the grid)
+        let cell = self.cells.next()?;
 
-            // Otherwise, build the grid cell element
-            _ => Some((self.current_column, self.current_row, self.gridvector[self.current_column][self.current_row].clone()))
-        };
+        // Calculate the position of the cell from its index
+        let (x, y) = (self.index % self.width, self.index / self.width);
+        // Increment the index and return the iteration element
+        self.index += 1;
+        Some((x, y, cell))
+    }
 
-        // Increment the row position
-        self.current_row += 1;
-        // Return the iteration element
-        return element;
+    /// A method that returns the bounds on the number of remaining cells
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.cells.size_hint()
     }
-}
\ No newline at end of file
+}
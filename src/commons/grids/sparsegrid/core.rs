@@ -0,0 +1,177 @@
+use std::collections::HashSet;
+
+use rand::Rng;
+use serde::{Serialize, Deserialize};
+
+use crate::{Error, Result};
+use crate::commons::cells::BinaryCell;
+use crate::commons::navigation::Direction4;
+use crate::simulation::SimGrid;
+use crate::commons::grids::{Boundary, CellGrid, GridPoint};
+
+/// A struct that represents an unbounded grid of binary cells that only
+/// stores the positions of its live cells. Every other cell is dead.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SparseGrid {
+    /// Represents the positions of the live cells
+    pub cells: HashSet<(i64, i64)>,
+    /// Represents the width of the frame of the grid (in cells)
+    pub width: usize,
+    /// Represents the height of the frame of the grid (in cells)
+    pub height: usize,
+}
+
+/// Implementation of the SimGrid trait for SparseGrid
+impl SimGrid for SparseGrid {
+    /// Define the type of cell in the grid
+    type Cell = BinaryCell;
+
+    /// Define the type of compass
+    type Orientation = Direction4;
+
+    /// A constructor method that creates a null grid.
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// A getter method that returns the name of the type of grid.
+    /// Ex: "Sparse Grid"
+    fn name(&self) -> String {
+        "Sparse Grid".to_string()
+    }
+
+    /// A setter method that sets the live cells of the given grid
+    /// to the struct. The frame is set to the dimensions of the grid.
+    fn setgrid(&mut self, other: CellGrid<Self::Cell>) {
+        self.width = other.getwidth();
+        self.height = other.getheight();
+        self.cells = other.iter()
+            .filter(|&(_, _, &cell)| cell == BinaryCell::Active)
+            .map(|(x, y, _)| (x as i64, y as i64))
+            .collect();
+    }
+
+    /// A setter method that sets the cell at the given position.
+    fn setcell(&mut self, x: isize, y: isize, cell: Self::Cell) -> bool {
+        self.set(x as i64, y as i64, cell);
+        true
+    }
+
+    /// A getter method that returns the height of the frame of the grid (number of rows).
+    fn getheight(&self) -> usize {
+        self.height
+    }
+
+    /// A getter method that returns the width of the frame of the grid (number of columns).
+    fn getwidth(&self) -> usize {
+        self.width
+    }
+
+    /// A getter method that returns the cell at the given position.
+    fn getcell(&self, x: isize, y: isize) -> Option<Self::Cell> {
+        Some(self.get(x as i64, y as i64))
+    }
+
+    /// A getter method that returns the boundary policy of the grid.
+    fn boundary(&self) -> Boundary {
+        Boundary::Dead
+    }
+
+    /// A setter method that sets the boundary policy of the grid.
+    fn setboundary(&mut self, _boundary: Boundary) {}
+
+    /// A method that returns a random cell from the frame of the grid.
+    /// Returns the x,y position of the cell along with cell state as
+    /// a GridPoint or an error if the frame of the grid has no cells.
+    fn randomcell<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<GridPoint<Self::Cell>> {
+        // Check if the frame has any cells
+        if self.width == 0 || self.height == 0 {
+            return Err(Error::EmptyGrid);
+        }
+
+        // Get a random column and row from the frame
+        let col = rng.gen_range(0..self.width) as isize;
+        let row = rng.gen_range(0..self.height) as isize;
+        // Build the GridPoint and return it
+        Ok((col, row, self.get(col as i64, row as i64)))
+    }
+
+    /// A method that returns the state in the given previous grid
+    /// of every cell that differs between it and the current grid.
+    fn changes(&self, previous: &Self) -> Vec<GridPoint<Self::Cell>> {
+        // Cells that were alive and have died
+        let died = previous.cells.difference(&self.cells)
+            .map(|&(x, y)| (x as isize, y as isize, BinaryCell::Active));
+        // Cells that were dead and have been born
+        let born = self.cells.difference(&previous.cells)
+            .map(|&(x, y)| (x as isize, y as isize, BinaryCell::Passive));
+
+        died.chain(born).collect()
+    }
+}
+
+/// Implementation of helper methods for SparseGrid.
+impl SparseGrid {
+    /// A getter method that returns the cell at the given coordinates.
+    pub fn get(&self, x: i64, y: i64) -> BinaryCell {
+        match self.cells.contains(&(x, y)) {
+            true => BinaryCell::Active,
+            false => BinaryCell::Passive,
+        }
+    }
+
+    /// A setter method that sets the cell at the given coordinates.
+    pub fn set(&mut self, x: i64, y: i64, cell: BinaryCell) {
+        match cell {
+            BinaryCell::Active => self.cells.insert((x, y)),
+            BinaryCell::Passive => self.cells.remove(&(x, y)),
+        };
+    }
+
+    /// A method that resizes the frame of the grid to the given dimensions (in cells).
+    pub fn resize(&mut self, width: usize, height: usize) {
+        self.width = width;
+        self.height = height;
+    }
+
+    /// A method that returns whether the given coordinates are within the frame of the grid.
+    pub fn inframe(&self, x: i64, y: i64) -> bool {
+        x >= 0 && y >= 0 && x < self.width as i64 && y < self.height as i64
+    }
+
+    /// A method that returns the bounding box of the live cells as the coordinates
+    /// of its top left and bottom right cells. Returns None if no cells are alive.
+    pub fn bounds(&self) -> Option<((i64, i64), (i64, i64))> {
+        let left = self.cells.iter().map(|&(x, _)| x).min()?;
+        let top = self.cells.iter().map(|&(_, y)| y).min()?;
+        let right = self.cells.iter().map(|&(x, _)| x).max()?;
+        let bottom = self.cells.iter().map(|&(_, y)| y).max()?;
+        Some(((left, top), (right, bottom)))
+    }
+
+    /// A method that counts the cells of the grid by state. Returns the number of
+    /// live cells (anywhere) and the number of dead cells within the frame as a tuple.
+    pub fn population(&self) -> (u32, u32) {
+        let inframe = self.cells.iter().filter(|&&(x, y)| self.inframe(x, y)).count();
+        let passive = (self.width * self.height).saturating_sub(inframe);
+        (self.cells.len() as u32, passive as u32)
+    }
+}
+
+/// Implementation of the IntoIterator trait for SparseGrid.
+/// Only the live cells of the grid are iterated over.
+impl IntoIterator for SparseGrid {
+    /// Define the iteration item type
+    type Item = GridPoint<BinaryCell>;
+    /// Define the iterator type
+    type IntoIter = std::vec::IntoIter<GridPoint<BinaryCell>>;
+
+    /// A method that creates an iterator over the live cells of the SparseGrid.
+    fn into_iter(self) -> Self::IntoIter {
+        self.cells.into_iter()
+            .map(|(x, y)| (x as isize, y as isize, BinaryCell::Active))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
@@ -0,0 +1,16 @@
+//! An unbounded grid of binary cells that only stores the positions of its live cells.
+//!
+//! Positions are i64 coordinates that may lie anywhere, including left of or above
+//! the origin. Through the SimGrid trait, positions are the same coordinates as isize.
+//!
+//! The frame of the grid is the region of ``width`` x ``height`` cells at the origin
+//! that the grid was initialized with. It is used to seed and place patterns and
+//! as the region that can be painted, but live cells are not bound to it. The grid
+//! has no edges, so boundary policies are ignored and resizing only changes the frame.
+
+pub mod core;
+pub mod pattern;
+#[cfg(feature = "graphics")]
+pub mod render;
+
+pub use crate::commons::grids::sparsegrid::core::SparseGrid;
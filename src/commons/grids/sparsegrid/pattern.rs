@@ -0,0 +1,59 @@
+use crate::commons::cells::BinaryCell;
+use crate::commons::grids::{GridPoint, Pattern, Placement, SparseGrid};
+
+/// Implementation of methods for Pattern on a SparseGrid.
+impl Pattern {
+    /// A constructor function that creates a pattern from the live cells of the given sparse grid.
+    /// If bounded is set, the pattern is cropped to the bounding box of the live cells,
+    /// otherwise the pattern spans the frame of the grid along with any live cells beyond it.
+    pub fn from_sparse(grid: &SparseGrid, bounded: bool) -> Self {
+        // Calculate the top left corner of the pattern
+        let (left, top) = match grid.bounds() {
+            None => (0, 0),
+            Some(((left, top), _)) if bounded => (left, top),
+            Some(((left, top), _)) => (left.min(0), top.min(0)),
+        };
+
+        // Collect the positions of the live cells relative to the corner (in row order)
+        let mut cells: Vec<(usize, usize)> = grid.cells.iter()
+            .map(|&(x, y)| ((x - left) as usize, (y - top) as usize))
+            .collect();
+        cells.sort_by_key(|&(x, y)| (y, x));
+
+        // Grow the pattern to hold the frame of the grid
+        let mut pattern = Self::new(cells);
+        if !bounded {
+            pattern.width = pattern.width.max((grid.width as i64 - left) as usize);
+            pattern.height = pattern.height.max((grid.height as i64 - top) as usize);
+        }
+
+        pattern
+    }
+}
+
+/// Implementation of pattern placement methods for SparseGrid.
+impl SparseGrid {
+    /// A method that returns the live cells that place the given pattern onto the grid.
+    /// Centred patterns are centred on the frame, but are never clipped to it.
+    pub fn placement(&self, pattern: &Pattern, placement: Placement) -> Vec<GridPoint<BinaryCell>> {
+        let (width, height) = (self.width as i64, self.height as i64);
+
+        // Calculate the position of the top left corner of the pattern
+        let (left, top) = match placement {
+            Placement::Centre => ((width - pattern.width as i64) / 2, (height - pattern.height as i64) / 2),
+            Placement::At(x, y) => (x as i64, y as i64),
+        };
+
+        // Collect the live cells of the pattern
+        pattern.cells.iter()
+            .map(|&(x, y)| ((left + x as i64) as isize, (top + y as i64) as isize, BinaryCell::Active))
+            .collect()
+    }
+
+    /// A method that places the given pattern onto the grid.
+    pub fn place(&mut self, pattern: &Pattern, placement: Placement) {
+        for (x, y, cell) in self.placement(pattern, placement) {
+            self.set(x as i64, y as i64, cell);
+        }
+    }
+}
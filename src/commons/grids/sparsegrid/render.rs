@@ -0,0 +1,58 @@
+use ggez::graphics;
+
+use crate::commons::grids::SparseGrid;
+
+/// Implementation of rendering methods for SparseGrid.
+impl SparseGrid {
+    /// A method that adds the live cells of the grid within the visible region (in grid space)
+    /// to the given mesh builder. Live cells are filled white and the frame of the grid is
+    /// outlined with a faint boundary. Dead cells are left to the background.
+    pub fn render(&self, mb: &mut graphics::MeshBuilder, cellsize: f32, visible: graphics::Rect) {
+        // Add the outline of the frame to the mesh builder
+        mb.rectangle(
+            graphics::DrawMode::Stroke(graphics::StrokeOptions::default()),
+            graphics::Rect::new(0.0, 0.0, self.width as f32 * cellsize, self.height as f32 * cellsize),
+            [1.0, 1.0, 1.0, 0.25].into(),
+        );
+
+        // Iterate through each live cell of the grid
+        for &(x, y) in &self.cells {
+            // Create the bounds of the cell
+            let cellbounds = graphics::Rect::new(
+                (x as f32) * cellsize,
+                (y as f32) * cellsize,
+                cellsize,
+                cellsize,
+            );
+
+            // Add the cell fill to the mesh builder if it is visible
+            if visible.overlaps(&cellbounds) {
+                mb.rectangle(
+                    graphics::DrawMode::Fill(graphics::FillOptions::default()),
+                    cellbounds,
+                    [1.0, 1.0, 1.0, 1.0].into(),
+                );
+            }
+        }
+    }
+
+    /// A method that returns the region (in grid space) covered by the frame
+    /// of the grid and all of its live cells.
+    pub fn extent(&self, cellsize: f32) -> graphics::Rect {
+        // Grow the frame to hold the bounding box of the live cells
+        let ((left, top), (right, bottom)) = match self.bounds() {
+            None => ((0, 0), (self.width as i64 - 1, self.height as i64 - 1)),
+            Some(((left, top), (right, bottom))) => (
+                (left.min(0), top.min(0)),
+                (right.max(self.width as i64 - 1), bottom.max(self.height as i64 - 1)),
+            ),
+        };
+
+        graphics::Rect::new(
+            left as f32 * cellsize,
+            top as f32 * cellsize,
+            (right - left + 1) as f32 * cellsize,
+            (bottom - top + 1) as f32 * cellsize,
+        )
+    }
+}
@@ -1,3 +1,5 @@
+pub mod bands;
 pub mod cells;
 pub mod grids;
-pub mod navigation;
\ No newline at end of file
+pub mod navigation;
+pub mod random;
\ No newline at end of file
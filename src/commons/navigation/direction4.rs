@@ -1,4 +1,5 @@
 use rand::Rng;
+use serde::{Serialize, Deserialize};
 use crate::commons::navigation::Orient;
 
 /// An enum that represents the cardinal directions.
@@ -8,7 +9,7 @@ use crate::commons::navigation::Orient;
 /// - ``Direction4::South`` <- represents the south direction
 /// - ``Direction4::East`` <- represents the east direction
 /// - ``Direction4::West`` <- represents the west direction
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub enum Direction4 {
     /// Represents the north direction.
     North = 0,
@@ -22,10 +23,10 @@ pub enum Direction4 {
 
 impl Orient for Direction4 {
     /// A constructor function that generates a new Direction4 with a random direction
-    fn random() -> Self {
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
         // Randomly generate a number between 0 and 3 (inclusive)
         // and return the corresponding Direction
-        rand::thread_rng().gen_range(0..=3).into()
+        rng.gen_range(0..=3).into()
     }  
 
     /// A method that rotates the Direction 90 degrees clockwise and returns a new Direction.
@@ -62,6 +63,20 @@ impl Orient for Direction4 {
     }
 }
 
+/// Implementation of helper methods for Direction4
+impl Direction4 {
+    /// A method that returns the offset of one unit in the direction as (dx, dy).
+    /// North is towards increasing y and east is towards increasing x.
+    pub fn offset(&self) -> (isize, isize) {
+        match self {
+            Direction4::North => (0, 1),
+            Direction4::East => (1, 0),
+            Direction4::South => (0, -1),
+            Direction4::West => (-1, 0),
+        }
+    }
+}
+
 /// Implementation of the From<i32> trait for Direction4
 impl From<i32> for Direction4 {
     /// A method that converts an i32 into a Direction
@@ -1,3 +1,5 @@
+use rand::Rng;
+
 mod turmite;
 mod direction4;
 
@@ -7,8 +9,8 @@ pub use direction4::Direction4;
 /// A trait for grid orientation.
 /// The orientation must be cloneable
 pub trait Orient: Clone {
-    /// A constructor method that generates a random orientation
-    fn random() -> Self;
+    /// A constructor method that generates a random orientation from the given generator
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self;
 
     /// A method that rotates the orientation clockwise
     fn turn_right(&self) -> Self;
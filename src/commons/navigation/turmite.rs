@@ -1,17 +1,26 @@
+use rand::Rng;
+use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
+
+use crate::Result;
 use crate::simulation::SimGrid;
-use crate::commons::grids::GridCell;
+use crate::commons::grids::{Boundary, GridPoint};
 use crate::commons::navigation::Orient;
-use crate::commons::grids::CellGrid;
+use crate::commons::grids::{CellGrid, SparseGrid};
 use crate::commons::cells::BinaryCell;
-use crate::commons::navigation::Direction4;
 
 /// A struct that represents an automaton turmite.
 /// i.e an agent that crawls the simulation grid
+#[derive(Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "T::Orientation: Serialize, T::Cell: Serialize",
+    deserialize = "T::Orientation: DeserializeOwned, T::Cell: DeserializeOwned",
+))]
 pub struct Turmite<T> where T: SimGrid {
     /// Represents the current orientation.
     pub orientation: T::Orientation,
     /// Represents the current location on the grid
-    pub position: GridCell<T::Cell>, 
+    pub position: GridPoint<T::Cell>, 
     /// Represents whether the turmite is alive
     pub active: bool,
     /// Represents the number of steps the turmite has been alive
@@ -20,15 +29,16 @@ pub struct Turmite<T> where T: SimGrid {
 
 /// Implementation of Turmite constructor
 impl<T: SimGrid> Turmite<T> {
-    /// A constructor that initializes the turmite 
-    /// with a random orientation and location.
-    pub fn new(grid: &T) -> Self {
-        Self{
-            orientation: T::Orientation::random(),
-            position: grid.randomcell(),
+    /// A constructor that initializes the turmite with a random
+    /// orientation and location from the given generator.
+    /// Returns an error if the grid has no cells to place the turmite on.
+    pub fn new<R: Rng + ?Sized>(grid: &T, rng: &mut R) -> Result<Self> {
+        Ok(Self{
+            orientation: T::Orientation::random(rng),
+            position: grid.randomcell(rng)?,
             active: true,
             step: 0,
-        }
+        })
     }
 }
 
@@ -46,8 +56,8 @@ impl<T:SimGrid> Clone for Turmite<T> {
 }
 
 /// Implementation of helper methods for a Turmite
-/// on CellGrid with Binary cells.
-impl Turmite<CellGrid<BinaryCell>> {
+/// on any grid with Binary cells.
+impl<T: SimGrid<Cell = BinaryCell>> Turmite<T> {
     /// A method that returns the flipped cell state
     /// of the current cell the turmite is on.
     pub fn flipcell(&mut self) -> BinaryCell {
@@ -63,64 +73,75 @@ impl Turmite<CellGrid<BinaryCell>> {
         // Update the turmite's position with the new cell state
         self.position = (x,y, newcell);
         // Return the new cell state
-        return newcell
+        newcell
     }
+}
 
-    // A method that moves the turmite forward one step in the current direction
-    // Makes no change if the turmite is at the edge of the grid and kills the turmite.
-    pub fn move_forward(&mut self, grid: &Vec<Vec<BinaryCell>>) {
-        // Get the cell state and position of the ant
+/// Implementation of helper methods for a Turmite
+/// on CellGrid with Binary cells.
+impl Turmite<CellGrid<BinaryCell>> {
+    /// A method that moves the turmite forward one step in the current direction.
+    /// At the edge of the grid the move follows the boundary policy of the grid:
+    /// - ``Boundary::Dead`` makes no change and kills the turmite.
+    /// - ``Boundary::Toroidal`` moves the turmite to the opposite edge.
+    /// - ``Boundary::Reflective`` keeps the turmite in place and turns it around.
+    pub fn move_forward(&mut self, grid: &CellGrid<BinaryCell>) {
+        // Get the position of the ant and the dimensions and boundary policy of the grid
         let (x, y, _) = self.position;
+        let (width, height, boundary) = (grid.getwidth(), grid.getheight(), grid.boundary);
 
-        // Check the orientation of the ant and move it one unit forward
-        match self.orientation {
-            // If the ant is facing north
-            Direction4::North => {
-                // Check that incremented value is within the grid bounds
-                if y+1 < grid[0].len() {
-                    // Increment the y position of the ant
-                    self.position = (x, y+1, grid[x][y+1]);
-                } else {
-                    // If the y position is out of bounds, set the ant to inactive
-                    self.active = false;
-                }
-            },
+        // Calculate the position one unit forward in the current direction
+        let (dx, dy) = self.orientation.offset();
+        let (nx, ny) = (x + dx, y + dy);
+        // Check if the position is beyond the edge of the grid
+        let beyond = nx < 0 || ny < 0 || nx >= width as isize || ny >= height as isize;
 
-            // If the ant is facing east
-            Direction4::East => {
-                // Check that incremented value is within the grid bounds
-                if x+1 < grid.len() {
-                    // Increment the x position of the ant
-                    self.position = (x+1, y, grid[x+1][y]);
-                } else {
-                    // If the x position is out of bounds, set the ant to inactive
-                    self.active = false;
+        // Resolve the position with the boundary policy
+        match boundary.resolve(nx, ny, width, height) {
+            // If the position is beyond a dead edge, set the ant to inactive
+            None => self.active = false,
+            // Otherwise move the ant to the resolved position
+            Some((nx, ny)) => {
+                // Turn the ant around if it was reflected at the edge
+                if beyond && boundary == Boundary::Reflective {
+                    self.orientation = self.orientation.turn_around();
                 }
-            },
+                self.position = (nx as isize, ny as isize, grid[(nx, ny)]);
+            }
+        }
+    }
 
-            // If the ant is facing south
-            Direction4::South => {
-                // Check if y is 0, decrement will result in out of bounds and a stack overflow
-                if y == 0 {
-                    // Set the ant to inactive
-                    self.active = false;
-                } else {
-                    // Decrement the y position of the ant
-                    self.position = (x, y-1, grid[x][y-1]);
-                }
-            },
+    /// A method that moves the turmite onto the nearest cell of the grid if it is beyond
+    /// the edges of the grid (ex: after the grid is resized). The cell under the turmite
+    /// is read from the grid. Makes no change if the grid has no cells.
+    pub fn confine(&mut self, grid: &CellGrid<BinaryCell>) {
+        // Check if the grid has any cells
+        let (width, height) = (grid.getwidth(), grid.getheight());
+        if width == 0 || height == 0 {
+            return;
+        }
 
-            // If the ant is facing west
-            Direction4::West => {
-                // Check if x is 0, decrement will result in out of bounds and a stack overflow
-                if x == 0 {
-                    // Set the ant to inactive
-                    self.active = false;
-                } else {
-                    // Decrement the x position of the ant
-                    self.position = (x-1, y, grid[x-1][y]);
-                }
-            },
-        };
+        // Clamp the position of the turmite onto the grid
+        let (x, y, _) = self.position;
+        let (x, y) = (x.clamp(0, width as isize - 1) as usize, y.clamp(0, height as isize - 1) as usize);
+        self.position = (x as isize, y as isize, grid[(x, y)]);
+    }
+}
+
+/// Implementation of helper methods for a Turmite
+/// on SparseGrid with Binary cells.
+impl Turmite<SparseGrid> {
+    /// A method that moves the turmite forward one step in the current direction.
+    /// The grid is unbounded, so the turmite never reaches an edge.
+    pub fn move_forward(&mut self, grid: &SparseGrid) {
+        // Get the position of the ant
+        let (x, y, _) = self.position;
+
+        // Calculate the position one unit forward in the current direction
+        let (dx, dy) = self.orientation.offset();
+        let (nx, ny) = (x + dx, y + dy);
+
+        // Move the ant to the new position
+        self.position = (nx, ny, grid.get(nx as i64, ny as i64));
     }
-}
\ No newline at end of file
+}
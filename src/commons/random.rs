@@ -0,0 +1,15 @@
+use rand::SeedableRng;
+
+/// A type alias for the random number generator used by the simulations.
+/// ChaCha8 produces the same sequence for a seed on every platform and release.
+pub type SimRng = rand_chacha::ChaCha8Rng;
+
+/// A function that creates a random number generator from the given seed.
+pub fn seeded(seed: u64) -> SimRng {
+    SimRng::seed_from_u64(seed)
+}
+
+/// A function that generates a new seed from the system entropy source.
+pub fn entropy_seed() -> u64 {
+    rand::random()
+}
@@ -0,0 +1,99 @@
+use std::fmt;
+use std::io;
+
+/// A type alias for results returned by the crate.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// An enum that represents the errors returned by the crate.
+#[derive(Debug)]
+pub enum Error {
+    /// Represents an initial state that is not supported by an automaton.
+    InvalidInitialState {
+        /// Represents the name of the automaton.
+        automaton: String,
+        /// Represents the initial state that was requested.
+        state: String,
+    },
+    /// Represents grid dimensions (in cells) that cannot hold a grid.
+    InvalidDimensions {
+        /// Represents the requested width of the grid.
+        width: usize,
+        /// Represents the requested height of the grid.
+        height: usize,
+    },
+    /// Represents a cell size (pixels) that is not a positive number.
+    InvalidCellSize(f32),
+    /// Represents a boundary policy name that is not supported by a grid.
+    InvalidBoundary(String),
+    /// Represents a rule that is not in the birth/survival notation.
+    InvalidRule(String),
+    /// Represents an option that is not supported by an automaton.
+    Unsupported {
+        /// Represents the name of the automaton.
+        automaton: String,
+        /// Represents the option that was requested.
+        option: String,
+    },
+    /// Represents a pattern file that could not be parsed or written.
+    InvalidPattern(String),
+    /// Represents a skew value that is not supported by a cell.
+    InvalidSkew(String),
+    /// Represents an operation that requires a grid being applied to a null or empty grid.
+    EmptyGrid,
+    /// Represents a snapshot that was saved from a different automaton.
+    SnapshotMismatch {
+        /// Represents the name of the automaton that was expected.
+        expected: String,
+        /// Represents the name of the automaton in the snapshot.
+        found: String,
+    },
+    /// Represents an error while reading or writing a file.
+    Io(io::Error),
+    /// Represents an error while encoding or decoding a snapshot.
+    Json(serde_json::Error),
+}
+
+/// Implementation of the Display trait for Error
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::InvalidInitialState { automaton, state } => write!(f, "invalid initial state '{}' for '{}'", state, automaton),
+            Error::InvalidDimensions { width, height } => write!(f, "invalid grid dimensions {}x{}. grid must be at least 1x1 cells", width, height),
+            Error::InvalidCellSize(size) => write!(f, "invalid cell size {}. cell size must be a positive number", size),
+            Error::InvalidBoundary(name) => write!(f, "invalid boundary '{}'. must be one of dead, toroidal or reflective", name),
+            Error::InvalidRule(rule) => write!(f, "invalid rule '{}'. must be in the B3/S23 notation", rule),
+            Error::Unsupported { automaton, option } => write!(f, "'{}' does not support {}", automaton, option),
+            Error::InvalidPattern(reason) => write!(f, "{}", reason),
+            Error::InvalidSkew(skew) => write!(f, "invalid skew value '{}'", skew),
+            Error::EmptyGrid => write!(f, "the grid is empty"),
+            Error::SnapshotMismatch { expected, found } => write!(f, "snapshot is of '{}' and not '{}'", found, expected),
+            Error::Io(err) => write!(f, "{}", err),
+            Error::Json(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+/// Implementation of the Error trait for Error
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            Error::Json(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Implementation of the conversion from an IO error
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+/// Implementation of the conversion from a JSON error
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::Json(err)
+    }
+}
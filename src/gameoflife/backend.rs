@@ -0,0 +1,250 @@
+use crate::{Error, Result};
+use crate::simulation::{SimGrid, Automaton};
+use crate::commons::cells::BinaryCell;
+use crate::commons::grids::{Boundary, CellGrid, GridPoint, Pattern, Placement};
+use crate::commons::random;
+use crate::gameoflife::{GameOfLife, Rule};
+
+/// A trait for the grids that the game of life runs on.
+pub trait LifeGrid: SimGrid<Cell = BinaryCell> {
+    /// A method that counts the cells of the grid by state.
+    /// Returns the number of alive and dead cells as a tuple.
+    fn population(&self) -> (u32, u32);
+
+    /// A method that returns the live cells that place the given pattern onto the grid.
+    fn placement(&self, pattern: &Pattern, placement: Placement) -> Vec<GridPoint<BinaryCell>>;
+
+    /// A method that places the given pattern onto the grid.
+    fn place(&mut self, pattern: &Pattern, placement: Placement);
+}
+
+/// A trait for the parts of the game of life that depend on its grid backend.
+/// The rest of the Automaton trait is shared by every backend.
+pub trait Backend {
+    /// A method that advances the game of life to the next generation.
+    fn advance(&mut self);
+
+    /// A method that advances the game of life by the given number of generations at once.
+    /// Defaults to advancing one generation at a time.
+    fn advance_by(&mut self, generations: u32) {
+        for _ in 0..generations {
+            self.advance();
+        }
+    }
+
+    /// A setter method that sets the cell at the given position of the grid.
+    /// Updates the alive and dead counters if the cell changes state.
+    fn setcell(&mut self, x: isize, y: isize, cell: BinaryCell);
+
+    /// A setter method that sets the policy for positions beyond the edges of the grid.
+    fn setboundary(&mut self, boundary: Boundary) -> Result<()>;
+
+    /// A method that resizes the grid to the given dimensions (in cells).
+    fn resize(&mut self, width: usize, height: usize) -> Result<()>;
+
+    /// A setter method that sets the birth/survival rule of the automaton (ex: "B36/S23").
+    fn setrule(&mut self, rule: &str) -> Result<()>;
+
+    /// A method that returns the live cells of the grid as a pattern with the rule of the automaton.
+    fn pattern(&self, bounded: bool) -> Result<Pattern>;
+}
+
+/// Implementation of the Automaton trait for GameOfLife on any grid backend.
+impl<T: LifeGrid> Automaton for GameOfLife<T> where Self: Backend {
+    /// Defines the type of grid for the automaton.
+    type Grid = T;
+
+    /// Defines the type of checkpoint for the automaton.
+    /// Holds the generation, alive and dead counters and the stable flag.
+    type Checkpoint = (u32, u32, u32, bool);
+
+    /// A constructor method that creates a null automaton
+    /// ands sets the initial state and seed parameters.
+    fn new(initialstate: &str, seed: u64) -> Self {
+        Self {
+            grid: T::new(),
+            initialstate: initialstate.to_string(),
+            rule: Rule::default(),
+            seed,
+            generation: 0,
+            alive: 0,
+            dead: 0,
+            stable: false,
+        }
+    }
+
+    /// A method that initializes the automaton for the given grid size (in cells).
+    fn initialize(&mut self, width: usize, height: usize) -> Result<()> {
+        // Create the random number generator from the seed
+        let mut rng = random::seeded(self.seed);
+
+        // Check the value of the initial state field
+        match self.initialstate.as_str() {
+            // Default initial state (random-balanced) or Balanced Random initial state
+            "default" | "random-balanced" => {
+                // Set the initial state string of the automaton
+                self.initialstate = "Random [1:1]".to_string();
+
+                // Create a grid of random cells with a balanced ratio of dead and alive cells
+                let randomgrid = CellGrid::<BinaryCell>::generate_randomgrid_balanced(width, height, &mut rng)?;
+                // Set the generated grid to the automaton grid
+                self.grid.setgrid(randomgrid);
+            },
+            // Empty initial state
+            "empty" => {
+                // Set the initial state string of the automaton
+                self.initialstate = "Empty".to_string();
+
+                // Create a grid of passive cells
+                let emptygrid = CellGrid::<BinaryCell>::generate_empty_grid(width, height)?;
+                // Set the generated grid to the automaton grid
+                self.grid.setgrid(emptygrid);
+            },
+
+            // Built-in pattern initial state ("{name}" or "{name}@{x}x{y}")
+            state => match Self::builtin_state(state) {
+                Some((pattern, placement)) => {
+                    // Set the initial state string of the automaton
+                    self.initialstate = format!("Pattern [{}]", pattern.name.as_deref().unwrap_or(state));
+
+                    // Create a grid of passive cells and place the pattern onto it
+                    let emptygrid = CellGrid::<BinaryCell>::generate_empty_grid(width, height)?;
+                    self.grid.setgrid(emptygrid);
+                    self.grid.place(&pattern, placement);
+                },
+
+                // Invalid initial state
+                None => {
+                    // Return an error
+                    return Err(Error::InvalidInitialState {
+                        automaton: "gameoflife".to_string(),
+                        state: self.initialstate.clone(),
+                    });
+                }
+            },
+        }
+
+        // Count the alive and dead cells of the initial state
+        let (alive, dead) = self.grid.population();
+        self.alive = alive;
+        self.dead = dead;
+
+        Ok(())
+    }
+
+    /// A method that advances the game of life to the next generation.
+    fn advance(&mut self) {
+        Backend::advance(self);
+    }
+
+    /// A method that advances the game of life by the given number of generations at once.
+    fn advance_by(&mut self, generations: u32) {
+        Backend::advance_by(self, generations);
+    }
+
+    /// A setter method that sets the cell at the given position of the grid.
+    fn setcell(&mut self, x: isize, y: isize, cell: BinaryCell) {
+        Backend::setcell(self, x, y, cell);
+    }
+
+    /// A getter method that returns the grid of the automaton.
+    fn grid(&self) -> &Self::Grid {
+        &self.grid
+    }
+
+    /// A setter method that sets the policy for positions beyond the edges of the grid.
+    fn setboundary(&mut self, boundary: Boundary) -> Result<()> {
+        Backend::setboundary(self, boundary)
+    }
+
+    /// A method that resizes the grid to the given dimensions (in cells).
+    fn resize(&mut self, width: usize, height: usize) -> Result<()> {
+        Backend::resize(self, width, height)
+    }
+
+    /// A setter method that sets the birth/survival rule of the automaton (ex: "B36/S23").
+    fn setrule(&mut self, rule: &str) -> Result<()> {
+        Backend::setrule(self, rule)
+    }
+
+    /// A method that places the given pattern onto the grid.
+    /// The initial state of the automaton is set to the name of the pattern.
+    fn place(&mut self, pattern: &Pattern, placement: Placement) -> Result<()> {
+        // Set the cells of the pattern (updates the counters)
+        for (x, y, cell) in self.grid.placement(pattern, placement) {
+            Backend::setcell(self, x, y, cell);
+        }
+
+        // Set the initial state string of the automaton
+        self.initialstate = format!("Pattern [{}]", pattern.name.as_deref().unwrap_or("Unnamed"));
+        Ok(())
+    }
+
+    /// A method that returns the live cells of the grid as a pattern with the rule of the automaton.
+    fn pattern(&self, bounded: bool) -> Result<Pattern> {
+        Backend::pattern(self, bounded)
+    }
+
+    /// A method that returns a checkpoint of the current state of the automaton.
+    fn checkpoint(&self) -> Self::Checkpoint {
+        (self.generation, self.alive, self.dead, self.stable)
+    }
+
+    /// A method that restores the automaton to the given checkpoint.
+    fn restore(&mut self, checkpoint: Self::Checkpoint) {
+        let (generation, alive, dead, stable) = checkpoint;
+        self.generation = generation;
+        self.alive = alive;
+        self.dead = dead;
+        self.stable = stable;
+    }
+
+    /// A getter method that returns the current generation of the automaton.
+    fn generation(&self) -> u32 {
+        self.generation
+    }
+
+    /// A getter method that returns the seed of the automaton.
+    fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// A getter method that returns the number of alive cells on the grid.
+    fn alive(&self) -> u32 {
+        self.alive
+    }
+
+    /// A getter method that returns the number of dead cells on the grid
+    /// (within the frame of unbounded grids).
+    fn dead(&self) -> u32 {
+        self.dead
+    }
+
+    /// A method that returns whether the game of life has halted.
+    /// The game halts once a generation leaves the grid unchanged.
+    fn halted(&self) -> bool {
+        self.stable
+    }
+
+    /// A method that returns the state of the automaton as a string.
+    /// Format: "Generation: {} | Alive: {} | Dead: {}"
+    fn state(&self) -> String {
+        format!("Generation: {} | Alive: {} | Dead: {}", self.generation, self.alive, self.dead)
+    }
+
+    /// A method that returns the name of the automaton as a string.
+    /// Format: "Conway's Game of Life" or "Life-like Automaton ({rule})" for other rules
+    fn name(&self) -> String {
+        match self.rule == Rule::conway() {
+            true => self.family().to_string(),
+            false => format!("{} ({})", self.family(), self.rule),
+        }
+    }
+
+    /// A method that returns the name of the automaton as a string
+    /// along with its rule, initial state and grid type.
+    /// Format: "Conway's Game of Life | {rule} | {grid} | {}" ("Life-like Automaton" for other rules)
+    fn fullname(&self) -> String {
+        format!("{} | {} | {} | {}", self.family(), self.rule, self.grid.name(), self.initialstate)
+    }
+}
@@ -0,0 +1,199 @@
+use crate::Result;
+use crate::simulation::SimGrid;
+use crate::commons::cells::BinaryCell;
+use crate::commons::grids::{BitGrid, Boundary, GridPoint, Pattern, Placement};
+use crate::gameoflife::{Backend, GameOfLife, LifeGrid};
+
+/// Implementation of the Backend trait for GameOfLife with a BitGrid grid.
+///
+/// The grid is advanced 64 cells at a time. The neighbours of the cells of a word are
+/// counted in parallel with bitwise adders and the rule is applied to the counts.
+impl Backend for GameOfLife<BitGrid> {
+    /// A method that advances the game of life to the next generation.
+    /// The cells are advanced a word (64 cells) at a time.
+    fn advance(&mut self) {
+        // Build the masks of the neighbour counts for which cells are born or survive
+        let birth: Vec<usize> = (0..9).filter(|&n| self.rule.born(n)).collect();
+        let survival: Vec<usize> = (0..9).filter(|&n| self.rule.survives(n)).collect();
+
+        // Step the grid into its back buffer a row at a time, counting the alive cells
+        // of each row and whether any of its cells changed state
+        let (alive, changed) = self.grid.step(|grid, y, next| {
+            // Declare counter variable for the number of alive cells
+            let mut alive: u32 = 0;
+            // Declare a flag for whether any cell changed state
+            let mut changed = false;
+
+            // Get the rows above and below the row (None beyond dead edges)
+            let row = grid.row(y);
+            let above = grid.neighbour(y as isize - 1);
+            let below = grid.neighbour(y as isize + 1);
+
+            for (k, word) in next.iter_mut().enumerate() {
+                // Count the live neighbours of the cells of the word as 4 bit planes
+                let mut counts = [0u64; 4];
+                for neighbours in [above, below].iter().flatten() {
+                    add(&mut counts, grid.west(neighbours, k));
+                    add(&mut counts, neighbours[k]);
+                    add(&mut counts, grid.east(neighbours, k));
+                }
+                add(&mut counts, grid.west(row, k));
+                add(&mut counts, grid.east(row, k));
+
+                // Keep the cells alive that survive and revive the cells that are born
+                let cells = row[k];
+                let survivors = survival.iter().fold(0, |mask, &n| mask | equals(&counts, n));
+                let births = birth.iter().fold(0, |mask, &n| mask | equals(&counts, n));
+                *word = ((cells & survivors) | (!cells & births)) & grid.mask(k);
+
+                // Check if the cells have changed state and count the alive cells
+                changed |= *word != cells;
+                alive += word.count_ones();
+            }
+
+            (alive, changed)
+        }, |(a, x), (b, y)| (a + b, x || y));
+
+        // Update the alive and dead cell value in the grid struct
+        self.alive = alive;
+        self.dead = (self.grid.getwidth() * self.grid.getheight()) as u32 - alive;
+        // Update the stable flag of the automaton
+        self.stable = !changed;
+        // Increment the generation value in the grid struct
+        self.generation += 1;
+    }
+
+    /// A setter method that sets the cell at the given position of the grid.
+    /// Updates the alive and dead counters if the cell changes state.
+    fn setcell(&mut self, x: isize, y: isize, cell: BinaryCell) {
+        // Get the current state of the cell (return if out of bounds)
+        let current = match self.grid.getcell(x, y) {
+            Some(current) => current,
+            None => return,
+        };
+
+        // Check if the cell changes state
+        if current != cell && self.grid.setcell(x, y, cell) {
+            // Update the alive and dead counters
+            match cell {
+                BinaryCell::Active => { self.alive += 1; self.dead -= 1; },
+                BinaryCell::Passive => { self.alive -= 1; self.dead += 1; },
+            }
+            // The grid is no longer known to be stable
+            self.stable = false;
+        }
+    }
+
+    /// A setter method that sets the policy for positions beyond the edges of the grid.
+    fn setboundary(&mut self, boundary: Boundary) -> Result<()> {
+        self.grid.setboundary(boundary);
+        // The grid is no longer known to be stable
+        self.stable = false;
+        Ok(())
+    }
+
+    /// A method that resizes the grid to the given dimensions (in cells).
+    /// The cells beyond the new edges are dropped and the new cells are dead.
+    fn resize(&mut self, width: usize, height: usize) -> Result<()> {
+        self.grid.resize(width, height);
+        // Recount the alive and dead cells of the resized grid
+        let (alive, dead) = self.grid.population();
+        self.alive = alive;
+        self.dead = dead;
+        // The grid is no longer known to be stable
+        self.stable = false;
+
+        Ok(())
+    }
+
+    /// A setter method that sets the birth/survival rule of the automaton (ex: "B36/S23").
+    fn setrule(&mut self, rule: &str) -> Result<()> {
+        self.rule = rule.parse()?;
+        // The grid is no longer known to be stable
+        self.stable = false;
+
+        Ok(())
+    }
+
+    /// A method that returns the live cells of the grid as a pattern with the rule of the automaton.
+    fn pattern(&self, bounded: bool) -> Result<Pattern> {
+        let mut pattern = Pattern::from_bitgrid(&self.grid, bounded);
+        pattern.rule = Some(self.rule.to_string());
+        Ok(pattern)
+    }
+}
+
+/// Implementation of the LifeGrid trait for BitGrid.
+impl LifeGrid for BitGrid {
+    /// A method that counts the cells of the grid by state.
+    fn population(&self) -> (u32, u32) {
+        BitGrid::population(self)
+    }
+
+    /// A method that returns the live cells that place the given pattern onto the grid.
+    fn placement(&self, pattern: &Pattern, placement: Placement) -> Vec<GridPoint<BinaryCell>> {
+        BitGrid::placement(self, pattern, placement)
+    }
+
+    /// A method that places the given pattern onto the grid.
+    fn place(&mut self, pattern: &Pattern, placement: Placement) {
+        BitGrid::place(self, pattern, placement)
+    }
+}
+
+// A function that adds the given word of bits to the bit planes of the given counts,
+// i.e. increments the count of each cell whose bit is set (a ripple carry adder per bit).
+fn add(counts: &mut [u64; 4], mut carry: u64) {
+    for plane in counts.iter_mut() {
+        let sum = *plane ^ carry;
+        carry &= *plane;
+        *plane = sum;
+    }
+}
+
+// A function that returns the bits of the cells whose count (from the given bit planes) equals n.
+fn equals(counts: &[u64; 4], n: usize) -> u64 {
+    counts.iter().enumerate().fold(u64::MAX, |mask, (i, &plane)| match (n >> i) & 1 {
+        1 => mask & plane,
+        _ => mask & !plane,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::simulation::Automaton;
+    use crate::commons::cells::BinaryCell;
+    use crate::commons::grids::{BitGrid, Boundary, CellGrid};
+    use crate::gameoflife::GameOfLife;
+
+    // Represents the number of generations that each grid is advanced
+    const GENERATIONS: usize = 40;
+
+    #[test]
+    fn packed_grid_matches_cell_grid() {
+        // The widths end just before, on and just after the edges of the words
+        for &width in &[63, 64, 65, 130] {
+            for &boundary in &[Boundary::Dead, Boundary::Toroidal, Boundary::Reflective] {
+                // Create the automata with the same seeded random grid
+                let mut cellgrid = GameOfLife::<CellGrid<BinaryCell>>::new("random-balanced", 7);
+                let mut bitgrid = GameOfLife::<BitGrid>::new("random-balanced", 7);
+                cellgrid.initialize(width, 17).unwrap();
+                bitgrid.initialize(width, 17).unwrap();
+                cellgrid.setboundary(boundary).unwrap();
+                bitgrid.setboundary(boundary).unwrap();
+
+                for generation in 1..=GENERATIONS {
+                    cellgrid.advance();
+                    bitgrid.advance();
+
+                    // Check that the automata have the same counters and cells
+                    assert_eq!(bitgrid.checkpoint(), cellgrid.checkpoint(), "width {} {} generation {}", width, boundary, generation);
+                    assert!(cellgrid.grid().clone().into_iter().eq(bitgrid.grid().clone()), "width {} {} generation {}", width, boundary, generation);
+                }
+
+                // Check that the grids are still alive, so that the check is not trivial
+                assert!(cellgrid.alive() > 0, "width {} {} died out", width, boundary);
+            }
+        }
+    }
+}
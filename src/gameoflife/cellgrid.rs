@@ -1,277 +1,140 @@
-use ggez::graphics;
-use ggez::GameResult;
-use ggez::nalgebra as na;
-
-use crate::simulation::{SimGrid, Automaton};
+use crate::Result;
+use crate::simulation::SimGrid;
 use crate::commons::cells::BinaryCell;
-use crate::commons::grids::CellGrid;
-use crate::gameoflife::GameOfLife;
-
-/// Implementation of the Automaton trait for GameOfLife with a CellGrid grid,
-impl Automaton for GameOfLife<CellGrid<BinaryCell>> {
-    /// Defines the type of grid for the automaton.
-    type Grid = CellGrid<BinaryCell>;
-
-    /// A constructor method that creates a null automaton
-    /// ands sets the initial state and cell size parameters.
-    fn new(initialstate: &str, cellsize: f32) -> Self {
-        Self {
-            grid: Self::Grid::new(cellsize),
-            initialstate: initialstate.to_string(),
-            cellsize,
-            generation: 0,
-            alive: 0,
-            dead: 0,
-        }
-    }
-
-    /// A method that initializes the automaton for the given dimensions.
-    fn initialize(&mut self, dimensions: graphics::Rect) {
-        // Create a new dimensions object for the grid of cells (60 px removed for the banner)
-        let griddimensions = graphics::Rect::new(0.0, 0.0, dimensions.w, dimensions.h - 60.0);
-        // Set the grid dimensions to the grid 
-        self.grid.setdimensions(griddimensions);
-        
-        // Check the value of the initial state field
-        match self.initialstate.as_str() {
-            // Default initial state (random-balanced)
-            "default" => {
-                // Set the initial state string of the automaton
-                self.initialstate = "Random [1:1]".to_string();
-
-                // Create a grid of random cells with a balanced ratio of dead and alive cells
-                let randomgrid = CellGrid::<BinaryCell>::generate_randomgrid_balanced(self.cellsize, griddimensions);
-                // Set the generated grid to the automaton grid
-                self.grid.setgrid(randomgrid);
-            },
-            // Balanced Random initial state
-            "random-balanced" => {
-                // Set the initial state string of the automaton
-                self.initialstate = "Random [1:1]".to_string();
-
-                // Create a grid of random cells with a balanced ratio of dead and alive cells
-                let randomgrid = CellGrid::<BinaryCell>::generate_randomgrid_balanced(self.cellsize, griddimensions);
-                // Set the generated grid to the automaton grid
-                self.grid.setgrid(randomgrid);
-            },
-
-            // Invalid initial state
-            _ => {
-                // Print an error and exit
-                eprintln!("[error] invalid initial state for 'gameoflife'");
-                std::process::exit(0);
-            }
-        }
-    }
+use crate::commons::grids::{Boundary, CellGrid, Neighbourhood, GridPoint, Pattern, Placement};
+use crate::gameoflife::{Backend, GameOfLife, LifeGrid};
 
+/// Implementation of the Backend trait for GameOfLife with a CellGrid grid.
+impl Backend for GameOfLife<CellGrid<BinaryCell>> {
     /// A method that advances the game of life to the next generation.
     fn advance(&mut self) {
-        // Declare counter variables for the number of alive and dead cells
-        let mut alive: u32 = 0;
-        let mut dead: u32 = 0;
-
-        // Check if the cell grid exists
-        if self.grid.vector.is_some() {
-            // Create a clone of the cell grid
-            let mut newgrid = self.grid.vector.clone().unwrap();
-
-            // Iterate over the grid
-            for (x, y, cell) in self.grid.clone() {
-
+        // Step the grid into its back buffer a row at a time, counting the alive cells
+        // of each row and whether any of its cells changed state
+        let rule = &self.rule;
+        let neighbourhood = Neighbourhood::moore();
+        let (alive, changed) = self.grid.step(|grid, y, next| {
+            // Declare counter variable for the number of alive cells
+            let mut alive: u32 = 0;
+            // Declare a flag for whether any cell changed state
+            let mut changed = false;
+
+            for (x, newcell) in next.iter_mut().enumerate() {
                 // Check the vicinity of the cell
-                let cell = match (cell, self.scan_vicinity(x, y)) {
-                    // If a cell is alive, and there are either too many live  
-                    // neighbors or not enough live neighbors, kill it.
-                    (BinaryCell::Active, n) if n < 2 || n > 3 => BinaryCell::Passive,
-
-                    // If a cell is alive and has either 2 
-                    // or 3 live neighbors, keep it alive
-                    (BinaryCell::Active, n) if n == 3 || n == 2 => BinaryCell::Active,
-
-                    // If a cell is dead and has exactly 3 live neighbors, revive it
-                    (BinaryCell::Passive, 3) => BinaryCell::Active,
-
-                    // Otherwise, keep the cell state
-                    (c, _) => c,
+                let cell = grid[(x, y)];
+                *newcell = match (cell, scan_vicinity(grid, x, y, &neighbourhood)) {
+                    // If a cell is alive and the rule allows it
+                    // to survive with n live neighbors, keep it alive
+                    (BinaryCell::Active, n) if rule.survives(n) => BinaryCell::Active,
+
+                    // If a cell is dead and the rule allows it
+                    // to be born with n live neighbors, revive it
+                    (BinaryCell::Passive, n) if rule.born(n) => BinaryCell::Active,
+
+                    // Otherwise, the cell dies or stays dead
+                    _ => BinaryCell::Passive,
                 };
 
-                // Add the new cell to the new grid
-                newgrid[x][y] = cell.clone();
-
-                // Increment the alive or dead counter
-                match cell {
-                    BinaryCell::Passive => dead += 1,
-                    BinaryCell::Active => alive += 1
-                }
+                // Check if the cell has changed state
+                changed |= *newcell != cell;
+                // Increment the alive counter
+                alive += (*newcell == BinaryCell::Active) as u32;
             }
 
-            // Assign the new grid to the grid struct
-            self.grid.setgrid(newgrid);
-        }
+            (alive, changed)
+        }, |(a, x), (b, y)| (a + b, x || y));
 
         // Update the alive and dead cell value in the grid struct
         self.alive = alive;
-        self.dead = dead;
+        self.dead = (self.grid.getwidth() * self.grid.getheight()) as u32 - alive;
+        // Update the stable flag of the automaton
+        self.stable = !changed;
         // Increment the generation value in the grid struct
         self.generation += 1;
-    }   
-
-    /// A method that returns the state of the automaton as a string.
-    /// Format: "Generation: {} | Alive: {} | Dead: {}"
-    fn state(&self) -> String {
-        format!("Generation: {} | Alive: {} | Dead: {}", self.generation, self.alive, self.dead)
     }
 
-    /// A method that returns the name of the automaton as a string.
-    /// Format: "Conway's Game of Life"
-    fn name(&self) -> String {
-        "Conway's Game of Life".to_string()
+    /// A setter method that sets the cell at the given position of the grid.
+    /// Updates the alive and dead counters if the cell changes state.
+    fn setcell(&mut self, x: isize, y: isize, cell: BinaryCell) {
+        // Get the current state of the cell (return if out of bounds)
+        let current = match self.grid.getcell(x, y) {
+            Some(current) => current,
+            None => return,
+        };
+
+        // Check if the cell changes state
+        if current != cell && self.grid.setcell(x, y, cell) {
+            // Update the alive and dead counters
+            match cell {
+                BinaryCell::Active => { self.alive += 1; self.dead -= 1; },
+                BinaryCell::Passive => { self.alive -= 1; self.dead += 1; },
+            }
+            // The grid is no longer known to be stable
+            self.stable = false;
+        }
     }
 
-    /// A method that returns the name of the automaton as a string 
-    /// along with its initial state and grid type.
-    /// Format: "Conway's Game of Life | Grid | {}"
-    fn fullname(&self) -> String {
-        format!("Conway's Game of Life | Grid | {}", self.initialstate)
+    /// A setter method that sets the policy for positions beyond the edges of the grid.
+    fn setboundary(&mut self, boundary: Boundary) -> Result<()> {
+        self.grid.setboundary(boundary);
+        // The grid is no longer known to be stable
+        self.stable = false;
+        Ok(())
     }
-}
-
-// Implementation of helper methods for GameOfLife with a CellGrid grid,
-impl GameOfLife<CellGrid<BinaryCell>> {
-    // A function that retrieves the number of alive cells in 
-    // the neighbouring vicity of a given cell (x, y)
-    fn scan_vicinity(&mut self, x: usize, y: usize) -> i32 {
-        // Declare a counter
-        let mut count = 0;
-        
-        // Check if the cell grid exists
-        if let Some(grid) = &self.grid.vector {
 
-            // Iterate over the cells in the vicinity of the cell at (x, y).
-            // The [-1,0,1] vectors represent the vicinity offsets for the x and y axis each.
-            for x_off in vec![-1, 0, 1] {
-                for y_off in vec![-1, 0, 1] {
-                    // Create the position of the cell in the 
-                    // grid based on the vicinity offsets
-                    let nx = x as i32 + x_off;
-                    let ny = y as i32 + y_off;
-                    
-                    // Check if position is out of grid bounds (x axis)
-                    if nx < 0 || nx >= grid.len() as i32 {
-                        continue;
-                    }
+    /// A method that resizes the grid to the given dimensions (in cells).
+    /// The cells beyond the new edges are dropped and the new cells are dead.
+    fn resize(&mut self, width: usize, height: usize) -> Result<()> {
+        self.grid.resize(width, height, BinaryCell::Passive);
+        // Recount the alive and dead cells of the resized grid
+        let (alive, dead) = self.grid.population();
+        self.alive = alive;
+        self.dead = dead;
+        // The grid is no longer known to be stable
+        self.stable = false;
 
-                    // Check if position is out of grid bounds (y axis)
-                    if ny < 0 || ny >= grid[nx as usize].len() as i32 {
-                        continue;
-                    }
+        Ok(())
+    }
 
-                    // Check if position points to the cell itself i.e (0,0) offsets
-                    if nx == x as i32 && ny == y as i32 {
-                        continue;
-                    }
+    /// A setter method that sets the birth/survival rule of the automaton (ex: "B36/S23").
+    fn setrule(&mut self, rule: &str) -> Result<()> {
+        self.rule = rule.parse()?;
+        // The grid is no longer known to be stable
+        self.stable = false;
 
-                    // Check if the cell if alive
-                    match grid[nx as usize][ny as usize].clone() {
-                        // Increment the counter if the cell is alive
-                        BinaryCell::Active => count = count+1,
-                        _ => continue,
-                    }
-                }
-            }
-        }
+        Ok(())
+    }
 
-        // Return the counter value
-        return count
+    /// A method that returns the live cells of the grid as a pattern with the rule of the automaton.
+    fn pattern(&self, bounded: bool) -> Result<Pattern> {
+        let mut pattern = Pattern::from_grid(&self.grid, bounded);
+        pattern.rule = Some(self.rule.to_string());
+        Ok(pattern)
     }
 }
 
-// Implementation of the Drawable trait for GameOfLife with a CellGrid grid,
-impl graphics::Drawable for GameOfLife<CellGrid<BinaryCell>> {
-
-    // A method that returns the dimensions of the automaton
-    fn dimensions(&self, _ctx: &mut ggez::Context) -> Option<graphics::Rect> {
-        // Get the grid dimesions and add the banner height
-        if let Some(dimensions) = &self.grid.dimensions {
-            Some(graphics::Rect::new(0.0, 0.0, dimensions.w, dimensions.h + 60.0))
-        } else {None}     
+/// Implementation of the LifeGrid trait for CellGrid.
+impl LifeGrid for CellGrid<BinaryCell> {
+    /// A method that counts the cells of the grid by state.
+    fn population(&self) -> (u32, u32) {
+        CellGrid::population(self)
     }
 
-    // A method that returns the graphics blending mode of the automaton grid
-    fn blend_mode(&self) -> Option<graphics::BlendMode> {
-        Some(graphics::BlendMode::Add)
+    /// A method that returns the live cells that place the given pattern onto the grid.
+    fn placement(&self, pattern: &Pattern, placement: Placement) -> Vec<GridPoint<BinaryCell>> {
+        CellGrid::placement(self, pattern, placement)
     }
 
-    // A method that set the graphics blend mode of the automaton grid (currently does nothing)
-    fn set_blend_mode(&mut self, _: Option<graphics::BlendMode>) {}
-
-    // A method that renders the automaton grid and state and returns a GameResult
-    fn draw(&self, ctx: &mut ggez::Context, param: graphics::DrawParam) -> GameResult<()> {
-        
-        // Create a new graphic mesh builder
-        let mut mb = graphics::MeshBuilder::new();
-
-        // Iterate through each cell in the grid
-        for (x, y, cell) in self.grid.clone() {
-
-            // Create the bounds of the cell
-            let cellbounds = graphics::Rect::new(
-                (x as f32) * self.cellsize,
-                (y as f32) * self.cellsize,
-                self.cellsize,
-                self.cellsize,
-            );
-
-            // Add the cell fill to the mesh builder
-            mb.rectangle(
-                graphics::DrawMode::Fill(graphics::FillOptions::default()),
-                cellbounds,
-                // Set the cell color based on cell state
-                match cell {
-                    BinaryCell::Passive => [0.0, 0.0, 0.0, 1.0].into(),
-                    BinaryCell::Active => [1.0, 1.0, 1.0, 1.0].into(),
-                },
-            )
-            // Add the cell boundary to the mesh builder
-            .rectangle(
-                graphics::DrawMode::Stroke(graphics::StrokeOptions::default()),
-                cellbounds,
-                [1.0, 1.0, 1.0, 0.25].into(),
-            );
-        }
-
-        // Build and Draw the mesh
-        mb.build(ctx)?.draw(ctx, param)?;
-
-        // Declare a variable for the font size
-        let font_size = 18.0;
-
-        // Create the text graphics for the banner
-        let mut name_text = graphics::Text::new(self.fullname());
-        let mut state_text = graphics::Text::new(self.state());
-
-        // Set the font styling for the text graphics
-        state_text.set_font(graphics::Font::default(), graphics::Scale::uniform(font_size));
-        name_text.set_font(graphics::Font::default(), graphics::Scale::uniform(font_size));
-
-        // Chekc the grid dimesions
-        if let Some(dimensions) = &self.grid.dimensions {
-            // Calculate the spacing between banner elements.
-            // Assumes 2 units of spacing above the name text and below the state text
-            // and 1 unit of spacing between the name and state text.
-            let spacing = (60.0 - (font_size * 2.0)) / 5.0;
-
-            // Calculate the position of the name text
-            let name_offset = dimensions.h + (spacing * 2.0);
-            // Calculate the position of the state text
-            let state_offset = dimensions.h + (spacing * 3.0) + font_size;
-
-            // Draw the banner text graphics
-            name_text.draw(ctx, (na::Point2::new(param.dest.x + 10.0, param.dest.y + name_offset),).into())?;
-            state_text.draw(ctx, (na::Point2::new(param.dest.x + 10.0, param.dest.y + state_offset),).into())?;
-        }
-
-        // Return GameResult::Ok
-        Ok(())
+    /// A method that places the given pattern onto the grid.
+    fn place(&mut self, pattern: &Pattern, placement: Placement) {
+        CellGrid::place(self, pattern, placement)
     }
-}
\ No newline at end of file
+}
+
+// A function that retrieves the number of alive cells in the
+// given neighbourhood of a given cell (x, y) of the grid
+fn scan_vicinity(grid: &CellGrid<BinaryCell>, x: usize, y: usize, neighbourhood: &Neighbourhood) -> usize {
+    // Count the alive neighbours of the cell (positions beyond dead edges are skipped)
+    grid.neighbours(x, y, neighbourhood)
+        .filter(|(_, _, cell)| **cell == BinaryCell::Active)
+        .count()
+}
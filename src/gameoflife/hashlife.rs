@@ -0,0 +1,255 @@
+use std::rc::Rc;
+
+use crate::{Error, Result};
+use crate::simulation::Automaton;
+use crate::commons::cells::BinaryCell;
+use crate::commons::grids::{Boundary, GridPoint, Pattern, Placement, QuadTree};
+use crate::commons::grids::quadtree::{Node, Store};
+use crate::gameoflife::{Backend, GameOfLife, LifeGrid, Rule};
+
+/// Represents the offsets of the 8 neighbours of a cell.
+const NEIGHBOURS: [(isize, isize); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+
+/// Implementation of the Backend trait for GameOfLife with a QuadTree grid,
+/// advanced with the HashLife algorithm (see ``advance_by``).
+impl Backend for GameOfLife<QuadTree> {
+    /// A method that advances the game of life to the next generation.
+    fn advance(&mut self) {
+        Backend::advance_by(self, 1);
+    }
+
+    /// A method that advances the game of life by the given number of generations at once.
+    /// The generations are split into powers of two that are each advanced in one step.
+    fn advance_by(&mut self, generations: u32) {
+        // Advance the root by each power of two in the number of generations
+        let before = self.grid.shrink(self.grid.root());
+        let mut root = before.clone();
+        for k in (0..u32::BITS as u8).rev() {
+            if generations >> k & 1 == 1 {
+                root = self.forward(&root, k);
+            }
+        }
+
+        // The grid is stable if it is unchanged and would be unchanged by the next generation
+        // (a grid that is unchanged after many generations may be an oscillator)
+        self.stable = Rc::ptr_eq(&root, &before) && Rc::ptr_eq(&self.forward(&root, 0), &root);
+        // Assign the new root to the grid and release unused nodes
+        self.grid.setroot(root);
+        self.grid.store().borrow_mut().trim();
+
+        // Update the alive and dead cell value in the grid struct
+        let (alive, dead) = self.grid.population();
+        self.alive = alive;
+        self.dead = dead;
+        // Increment the generation value in the grid struct
+        self.generation = self.generation.saturating_add(generations);
+    }
+
+    /// A setter method that sets the cell at the given position of the grid.
+    /// Updates the alive and dead counters if the cell changes state.
+    fn setcell(&mut self, x: isize, y: isize, cell: BinaryCell) {
+        // Check if the cell changes state
+        let (cx, cy) = (x as i64, y as i64);
+        if self.grid.get(cx, cy) != cell {
+            self.grid.set(cx, cy, cell);
+
+            // Update the alive counter (and the dead counter within the frame)
+            let inframe = self.grid.inframe(cx, cy) as u32;
+            match cell {
+                BinaryCell::Active => { self.alive += 1; self.dead -= inframe; },
+                BinaryCell::Passive => { self.alive -= 1; self.dead += inframe; },
+            }
+            // The grid is no longer known to be stable
+            self.stable = false;
+        }
+    }
+
+    /// A setter method that sets the policy for positions beyond the edges of the grid.
+    /// Returns an error as the grid has no edges.
+    fn setboundary(&mut self, boundary: Boundary) -> Result<()> {
+        Err(Error::Unsupported { automaton: Automaton::name(self), option: format!("boundaries on an unbounded grid (given '{}')", boundary) })
+    }
+
+    /// A method that resizes the frame of the grid to the given dimensions (in cells).
+    fn resize(&mut self, width: usize, height: usize) -> Result<()> {
+        self.grid.resize(width, height);
+        // Recount the dead cells within the resized frame
+        let (alive, dead) = self.grid.population();
+        self.alive = alive;
+        self.dead = dead;
+
+        Ok(())
+    }
+
+    /// A setter method that sets the birth/survival rule of the automaton (ex: "B36/S23").
+    /// Rules with B0 are not supported as they would fill the unbounded grid at once.
+    /// The memoized results of the grid are dropped as they belong to the previous rule.
+    fn setrule(&mut self, rule: &str) -> Result<()> {
+        let parsed: Rule = rule.parse()?;
+        if parsed.born(0) {
+            return Err(Error::Unsupported {
+                automaton: Automaton::name(self),
+                option: format!("rules with B0 on an unbounded grid (given '{}')", rule),
+            });
+        }
+
+        self.rule = parsed;
+        self.grid.rebuild();
+        // The grid is no longer known to be stable
+        self.stable = false;
+
+        Ok(())
+    }
+
+    /// A method that returns the live cells of the grid as a pattern with the rule of the automaton.
+    fn pattern(&self, bounded: bool) -> Result<Pattern> {
+        let mut pattern = Pattern::from_quadtree(&self.grid, bounded);
+        pattern.rule = Some(self.rule.to_string());
+        Ok(pattern)
+    }
+}
+
+/// Implementation of the LifeGrid trait for QuadTree.
+impl LifeGrid for QuadTree {
+    /// A method that counts the cells of the grid by state.
+    fn population(&self) -> (u32, u32) {
+        QuadTree::population(self)
+    }
+
+    /// A method that returns the live cells that place the given pattern onto the grid.
+    fn placement(&self, pattern: &Pattern, placement: Placement) -> Vec<GridPoint<BinaryCell>> {
+        QuadTree::placement(self, pattern, placement)
+    }
+
+    /// A method that places the given pattern onto the grid.
+    fn place(&mut self, pattern: &Pattern, placement: Placement) {
+        QuadTree::place(self, pattern, placement)
+    }
+}
+
+// Implementation of helper methods for GameOfLife with a QuadTree grid,
+impl GameOfLife<QuadTree> {
+    // A method that returns the given root advanced by 2^k generations.
+    // The root is padded so that no live cell can leave the result.
+    fn forward(&self, root: &Rc<Node>, k: u8) -> Rc<Node> {
+        // Pad the root so that the live cells are within the centre quarter
+        // and the result (the centre half) is at least 2^k cells away from them
+        let padded = self.grid.pad(root, k + 3);
+        let result = successor(&mut self.grid.store().borrow_mut(), &self.rule, &padded, k);
+        self.grid.shrink(&result)
+    }
+}
+
+// A function that returns the centre of the given node (at one level lower) advanced
+// by 2^j generations with the given rule. The node must be at a level above j + 1.
+//
+// The centre is built from the results of the 9 overlapping subnodes of the node. At full
+// speed (j = level - 2) both passes over the subnodes advance by 2^(j-1) generations,
+// otherwise the first pass only takes the centre of the subnodes. The result is memoized.
+fn successor(store: &mut Store, rule: &Rule, node: &Rc<Node>, j: u8) -> Rc<Node> {
+    // Check if the result has been memoized
+    if let Some(result) = node.result(j) {
+        return result;
+    }
+
+    let result = match node.level {
+        // An empty node stays empty
+        _ if node.population == 0 => store.empty(node.level - 1),
+        // A 4x4 node is advanced one generation by the rule
+        2 => evolve(store, rule, node),
+        // Otherwise combine the results of the subnodes
+        level => {
+            let [nw, ne, sw, se] = node.quadrants();
+            let subnodes = [
+                nw.clone(), store.horizontal(nw, ne), ne.clone(),
+                store.vertical(nw, sw), store.centre(node), store.vertical(ne, se),
+                sw.clone(), store.horizontal(sw, se), se.clone(),
+            ];
+
+            // Advance the subnodes by 2^(j-1) generations at full speed, otherwise take their centre
+            let full = j == level - 2;
+            let parts = subnodes.map(|subnode| match full {
+                true => successor(store, rule, &subnode, j - 1),
+                false => store.centre(&subnode),
+            });
+
+            // Combine the parts into the 4 quadrants of the result and advance them
+            let step = if full { j - 1 } else { j };
+            let quadrants = [[0, 1, 3, 4], [1, 2, 4, 5], [3, 4, 6, 7], [4, 5, 7, 8]].map(|indices| {
+                let quadrant = store.node(indices.map(|i| parts[i].clone()));
+                successor(store, rule, &quadrant, step)
+            });
+            store.node(quadrants)
+        },
+    };
+
+    // Memoize the result
+    node.setresult(j, result.clone());
+    result
+}
+
+// A function that returns the centre 2x2 cells of the given 4x4 node advanced one generation by the rule.
+fn evolve(store: &mut Store, rule: &Rule, node: &Node) -> Rc<Node> {
+    // Read the cells of the node as rows
+    let mut cells = [[false; 4]; 4];
+    for (i, quadrant) in node.quadrants().iter().enumerate() {
+        for (k, cell) in quadrant.quadrants().iter().enumerate() {
+            cells[(i / 2) * 2 + k / 2][(i % 2) * 2 + k % 2] = cell.population > 0;
+        }
+    }
+
+    // Calculate the next state of a centre cell from its live neighbours
+    let next = |x: usize, y: usize| {
+        let count = NEIGHBOURS.iter()
+            .filter(|&&(dx, dy)| cells[(y as isize + dy) as usize][(x as isize + dx) as usize])
+            .count();
+
+        match cells[y][x] {
+            true => rule.survives(count),
+            false => rule.born(count),
+        }
+    };
+
+    let centre = [next(1, 1), next(2, 1), next(1, 2), next(2, 2)].map(|alive| store.cell(alive));
+    store.node(centre)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::simulation::Automaton;
+    use crate::commons::grids::{QuadTree, SparseGrid};
+    use crate::gameoflife::GameOfLife;
+
+    // Represents the number of generations that the pattern is advanced
+    const GENERATIONS: u32 = 1000;
+
+    #[test]
+    fn hashlife_matches_sparse_grid() {
+        // Create the automata with an r-pentomino on each grid
+        let mut sparse = GameOfLife::<SparseGrid>::new("r-pentomino", 0);
+        let mut jumped = GameOfLife::<QuadTree>::new("r-pentomino", 0);
+        let mut stepped = GameOfLife::<QuadTree>::new("r-pentomino", 0);
+        for automaton in [&mut jumped, &mut stepped] {
+            automaton.initialize(40, 40).unwrap();
+        }
+        sparse.initialize(40, 40).unwrap();
+
+        // Advance the sparse grid a generation at a time and the quadtrees
+        // with a single jump and a generation at a time
+        for _ in 0..GENERATIONS {
+            sparse.advance();
+            stepped.advance();
+        }
+        jumped.advance_by(GENERATIONS);
+
+        // Check that the automata reached the same generation with the same live cells
+        let expected: HashSet<(i64, i64)> = sparse.grid().cells.clone();
+        for automaton in [&jumped, &stepped] {
+            assert_eq!(automaton.generation(), GENERATIONS);
+            assert_eq!(automaton.alive(), sparse.alive());
+            assert_eq!(automaton.grid().cells().into_iter().collect::<HashSet<_>>(), expected);
+        }
+    }
+}
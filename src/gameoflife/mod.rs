@@ -1,20 +1,80 @@
 mod cellgrid;
+mod bitgrid;
+mod sparsegrid;
+mod hashlife;
+mod backend;
+#[cfg(feature = "graphics")]
+mod render;
+pub mod rule;
+
+pub use rule::Rule;
+pub use backend::{Backend, LifeGrid};
+
+/// Represents the initial states supported by the game of life. Built-in patterns
+/// are centred or placed at a position with the "{name}@{x}x{y}" form.
+pub const INITIAL_STATES: &[&str] = &[
+    "default", "random-balanced", "empty",
+    "block", "blinker", "toad", "beacon", "pulsar", "pentadecathlon",
+    "glider", "lwss", "mwss", "hwss",
+    "r-pentomino", "acorn", "diehard",
+    "gosper-glider-gun",
+];
+
+use serde::{Serialize, Deserialize};
 
 use crate::simulation::SimGrid;
+use crate::commons::grids::{Pattern, Placement};
 
 /// A struct that represents the automaton for 
 /// Conway's Game of Life on a generic grid.
+#[derive(Serialize, Deserialize)]
 pub struct GameOfLife<T> where T: SimGrid {
     /// Represents the automaton grid.
     grid: T,    
-    /// Represents the size of an individual cell.
-    cellsize: f32,
     /// Represents the intial state of the automaton.
     initialstate: String,
+    /// Represents the birth/survival rule of the automaton.
+    #[serde(default)]
+    rule: Rule,
+    /// Represents the seed of the automaton's random number generator.
+    seed: u64,
     /// Represents the number of times the grid has been updated
     generation: u32,
     /// Represents the number of cells that are alive
     alive: u32,
     /// Represents the number of cells that are dead
     dead: u32,
+    /// Represents whether the last generation left the grid unchanged
+    stable: bool,
+}
+
+// Implementation of helper methods for GameOfLife on a generic grid,
+impl<T: SimGrid> GameOfLife<T> {
+    // A method that returns the name of the family of automata of the rule
+    // (Conway's Game of Life for B3/S23 and a Life-like automaton otherwise).
+    fn family(&self) -> &'static str {
+        match self.rule == Rule::conway() {
+            true => "Conway's Game of Life",
+            false => "Life-like Automaton",
+        }
+    }
+
+    // A function that parses an initial state of a built-in pattern into the pattern and its
+    // placement. The state is either "{name}" (centred) or "{name}@{x}x{y}" (top left at x, y).
+    fn builtin_state(state: &str) -> Option<(Pattern, Placement)> {
+        // Split the name of the pattern from its position
+        let mut parts = state.splitn(2, '@');
+        let pattern = Pattern::builtin(parts.next()?)?;
+
+        // Parse the position of the pattern (centred if not given)
+        let placement = match parts.next() {
+            None => Placement::Centre,
+            Some(position) => {
+                let (x, y) = position.split_once('x')?;
+                Placement::At(x.parse().ok()?, y.parse().ok()?)
+            }
+        };
+
+        Some((pattern, placement))
+    }
 }
@@ -0,0 +1,65 @@
+use ggez::graphics;
+use ggez::GameResult;
+
+use crate::simulation::Renderable;
+use crate::commons::cells::BinaryCell;
+use crate::commons::grids::{BitGrid, CellGrid, QuadTree, SparseGrid};
+use crate::gameoflife::GameOfLife;
+
+// Implementation of the Renderable trait for GameOfLife with a CellGrid grid,
+impl Renderable for GameOfLife<CellGrid<BinaryCell>> {
+    // A method that renders the automaton grid into the mesh builder
+    fn render(&self, mb: &mut graphics::MeshBuilder, cellsize: f32, visible: graphics::Rect) -> GameResult {
+        // Add the visible cells of the grid to the mesh builder
+        self.grid.render(mb, cellsize, visible);
+
+        // Return GameResult::Ok
+        Ok(())
+    }
+}
+
+// Implementation of the Renderable trait for GameOfLife with a BitGrid grid,
+impl Renderable for GameOfLife<BitGrid> {
+    // A method that renders the automaton grid into the mesh builder
+    fn render(&self, mb: &mut graphics::MeshBuilder, cellsize: f32, visible: graphics::Rect) -> GameResult {
+        // Add the visible live cells of the grid to the mesh builder
+        self.grid.render(mb, cellsize, visible);
+
+        // Return GameResult::Ok
+        Ok(())
+    }
+}
+
+// Implementation of the Renderable trait for GameOfLife with a SparseGrid grid,
+impl Renderable for GameOfLife<SparseGrid> {
+    // A method that renders the automaton grid into the mesh builder
+    fn render(&self, mb: &mut graphics::MeshBuilder, cellsize: f32, visible: graphics::Rect) -> GameResult {
+        // Add the visible live cells of the grid to the mesh builder
+        self.grid.render(mb, cellsize, visible);
+
+        // Return GameResult::Ok
+        Ok(())
+    }
+
+    // A method that returns the region covered by the frame and the live cells of the grid
+    fn extent(&self, cellsize: f32) -> graphics::Rect {
+        self.grid.extent(cellsize)
+    }
+}
+
+// Implementation of the Renderable trait for GameOfLife with a QuadTree grid,
+impl Renderable for GameOfLife<QuadTree> {
+    // A method that renders the automaton grid into the mesh builder
+    fn render(&self, mb: &mut graphics::MeshBuilder, cellsize: f32, visible: graphics::Rect) -> GameResult {
+        // Add the visible live cells of the grid to the mesh builder
+        self.grid.render(mb, cellsize, visible);
+
+        // Return GameResult::Ok
+        Ok(())
+    }
+
+    // A method that returns the region covered by the frame and the live cells of the grid
+    fn extent(&self, cellsize: f32) -> graphics::Rect {
+        self.grid.extent(cellsize)
+    }
+}
@@ -0,0 +1,195 @@
+use std::fmt;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use serde::{Serialize, Deserialize};
+
+use crate::Error;
+
+/// A struct that represents the rule of a Life-like automaton in birth/survival notation.
+/// A dead cell is born if its number of alive neighbours is in the birth set and an
+/// alive cell survives if its number of alive neighbours is in the survival set.
+///
+/// Rules are written as "B3/S23" (Conway's Game of Life), with the
+/// traditional survival/birth notation "23/3" accepted when parsing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Rule {
+    /// Represents whether a dead cell with n alive neighbours is born (indexed by n).
+    birth: [bool; 9],
+    /// Represents whether an alive cell with n alive neighbours survives (indexed by n).
+    survival: [bool; 9],
+}
+
+/// Implementation of the Default trait for Rule
+impl Default for Rule {
+    /// A method that returns the rule of Conway's Game of Life (B3/S23).
+    fn default() -> Self {
+        Self::conway()
+    }
+}
+
+/// Implementation of methods for Rule
+impl Rule {
+    /// A constructor function that creates the rule of Conway's Game of Life (B3/S23).
+    pub fn conway() -> Self {
+        Self::new(&[3], &[2, 3])
+    }
+
+    /// A constructor function that creates a rule from the given numbers of alive
+    /// neighbours for birth and survival. Numbers above 8 are ignored.
+    pub fn new(birth: &[usize], survival: &[usize]) -> Self {
+        let mut rule = Self { birth: [false; 9], survival: [false; 9] };
+        for &n in birth.iter().filter(|&&n| n < 9) {
+            rule.birth[n] = true;
+        }
+        for &n in survival.iter().filter(|&&n| n < 9) {
+            rule.survival[n] = true;
+        }
+
+        rule
+    }
+
+    /// A method that returns whether a dead cell with the given number of alive neighbours is born.
+    pub fn born(&self, neighbours: usize) -> bool {
+        self.birth.get(neighbours).copied().unwrap_or(false)
+    }
+
+    /// A method that returns whether an alive cell with the given number of alive neighbours survives.
+    pub fn survives(&self, neighbours: usize) -> bool {
+        self.survival.get(neighbours).copied().unwrap_or(false)
+    }
+}
+
+// A function that parses a set of neighbour counts (digits 0-8) into a set of flags.
+fn parse_counts(value: &str, rule: &str) -> Result<[bool; 9], Error> {
+    let mut counts = [false; 9];
+    for c in value.chars() {
+        match c.to_digit(10) {
+            Some(n) if n < 9 => counts[n as usize] = true,
+            _ => return Err(Error::InvalidRule(rule.to_string())),
+        }
+    }
+
+    Ok(counts)
+}
+
+/// Implementation of the FromStr trait for Rule
+impl FromStr for Rule {
+    type Err = Error;
+
+    /// A method that parses a rule in the "B3/S23" or "23/3" notation.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        // Split the rule into its two halves
+        let parts = value.trim().split('/').collect::<Vec<&str>>();
+        if parts.len() != 2 {
+            return Err(Error::InvalidRule(value.to_string()));
+        }
+
+        // Check the prefixes of the halves
+        let (birth, survival) = match (parts[0].chars().next(), parts[1].chars().next()) {
+            // Birth/survival notation (B3/S23)
+            (Some('B' | 'b'), Some('S' | 's')) => (&parts[0][1..], &parts[1][1..]),
+            // Survival/birth notation with prefixes (S23/B3)
+            (Some('S' | 's'), Some('B' | 'b')) => (&parts[1][1..], &parts[0][1..]),
+            // Traditional survival/birth notation without prefixes (23/3)
+            _ => (parts[1], parts[0]),
+        };
+
+        Ok(Self {
+            birth: parse_counts(birth, value)?,
+            survival: parse_counts(survival, value)?,
+        })
+    }
+}
+
+/// Implementation of the Display trait for Rule
+impl fmt::Display for Rule {
+    /// A method that formats the rule in the "B3/S23" notation.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Collect the digits of a set of neighbour counts
+        let digits = |counts: &[bool; 9]| (0..9)
+            .filter(|&n| counts[n])
+            .map(|n| n.to_string())
+            .collect::<String>();
+
+        write!(f, "B{}/S{}", digits(&self.birth), digits(&self.survival))
+    }
+}
+
+/// Implementation of the conversion of a rule into a string (for serialization)
+impl From<Rule> for String {
+    fn from(rule: Rule) -> Self {
+        rule.to_string()
+    }
+}
+
+/// Implementation of the conversion of a string into a rule (for deserialization)
+impl TryFrom<String> for Rule {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gameoflife::Rule;
+
+    #[test]
+    fn parse_rules() {
+        // Represents the rules and the rule they parse to in the "B3/S23" notation (None if invalid)
+        let table: &[(&str, Option<&str>)] = &[
+            ("B3/S23", Some("B3/S23")),
+            ("S23/B3", Some("B3/S23")),
+            ("23/3", Some("B3/S23")),
+            ("B36/S23", Some("B36/S23")),
+            ("B/S", Some("B/S")),
+            ("/", Some("B/S")),
+            ("b3/s23", Some("B3/S23")),
+            ("s23/b36", Some("B36/S23")),
+            (" B3/S23 ", Some("B3/S23")),
+            ("B0/S8", Some("B0/S8")),
+            ("B9/S23", None),
+            ("B3/S239", None),
+            ("B33/S3223", Some("B3/S23")),
+            ("B63/S32", Some("B36/S23")),
+            ("", None),
+            ("B3", None),
+            ("B3S23", None),
+            ("B3/S23/C2", None),
+            ("B3/23", None),
+            ("X3/Y23", None),
+            ("B3a/S23", None),
+            ("B-1/S23", None),
+            ("life", None),
+        ];
+
+        for &(rule, expected) in table {
+            let parsed = rule.parse::<Rule>().ok().map(|rule| rule.to_string());
+            assert_eq!(parsed.as_deref(), expected, "rule '{}'", rule);
+        }
+    }
+
+    #[test]
+    fn display_round_trip() {
+        // Check that every rule with up to 2 birth and survival counts is parsed back from its display
+        for b in 0..9 {
+            for s in 0..9 {
+                let rule = Rule::new(&[b, (b + 4) % 9], &[s, (s * 2) % 9]);
+                assert_eq!(rule.to_string().parse::<Rule>().unwrap(), rule, "rule {}", rule);
+            }
+        }
+        assert_eq!(Rule::default().to_string(), "B3/S23");
+        assert_eq!(Rule::new(&[], &[]).to_string().parse::<Rule>().unwrap(), Rule::new(&[], &[]));
+    }
+
+    #[test]
+    fn born_and_survives() {
+        // Check the counts of Conway's Game of Life (counts beyond 8 are never born or survive)
+        let rule = Rule::conway();
+        assert_eq!((0..10).filter(|&n| rule.born(n)).collect::<Vec<_>>(), vec![3]);
+        assert_eq!((0..10).filter(|&n| rule.survives(n)).collect::<Vec<_>>(), vec![2, 3]);
+    }
+}
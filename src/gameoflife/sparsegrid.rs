@@ -0,0 +1,138 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{Error, Result};
+use crate::simulation::Automaton;
+use crate::commons::cells::BinaryCell;
+use crate::commons::grids::{Boundary, GridPoint, Pattern, Placement, SparseGrid};
+use crate::gameoflife::{Backend, GameOfLife, LifeGrid, Rule};
+
+/// Represents the offsets of the 8 neighbours of a cell.
+const NEIGHBOURS: [(i64, i64); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+
+/// Implementation of the Backend trait for GameOfLife with a SparseGrid grid.
+impl Backend for GameOfLife<SparseGrid> {
+    /// A method that advances the game of life to the next generation.
+    /// Only the live cells and their neighbours are visited.
+    fn advance(&mut self) {
+        // Count the live neighbours of every cell next to a live cell
+        let mut counts: HashMap<(i64, i64), usize> = HashMap::with_capacity(self.grid.cells.len() * 8);
+        for &(x, y) in &self.grid.cells {
+            for (dx, dy) in NEIGHBOURS.iter() {
+                *counts.entry((x + dx, y + dy)).or_insert(0) += 1;
+            }
+        }
+
+        // Collect the cells that are alive in the next generation
+        let mut newcells: HashSet<(i64, i64)> = counts.into_iter()
+            .filter(|&(position, n)| match self.grid.cells.contains(&position) {
+                // If a cell is alive, keep it alive if the rule allows
+                // it to survive with n live neighbors
+                true => self.rule.survives(n),
+                // If a cell is dead, revive it if the rule allows
+                // it to be born with n live neighbors
+                false => self.rule.born(n),
+            })
+            .map(|(position, _)| position)
+            .collect();
+
+        // Keep the live cells without any live neighbours if the rule allows them to survive
+        if self.rule.survives(0) {
+            for &(x, y) in &self.grid.cells {
+                if NEIGHBOURS.iter().all(|(dx, dy)| !self.grid.cells.contains(&(x + dx, y + dy))) {
+                    newcells.insert((x, y));
+                }
+            }
+        }
+
+        // Update the stable flag of the automaton
+        self.stable = newcells == self.grid.cells;
+        // Assign the new cells to the grid
+        self.grid.cells = newcells;
+
+        // Update the alive and dead cell value in the grid struct
+        let (alive, dead) = self.grid.population();
+        self.alive = alive;
+        self.dead = dead;
+        // Increment the generation value in the grid struct
+        self.generation += 1;
+    }
+
+    /// A setter method that sets the cell at the given position of the grid.
+    /// Updates the alive and dead counters if the cell changes state.
+    fn setcell(&mut self, x: isize, y: isize, cell: BinaryCell) {
+        // Check if the cell changes state
+        let (cx, cy) = (x as i64, y as i64);
+        if self.grid.get(cx, cy) != cell {
+            self.grid.set(cx, cy, cell);
+
+            // Update the alive counter (and the dead counter within the frame)
+            let inframe = self.grid.inframe(cx, cy) as u32;
+            match cell {
+                BinaryCell::Active => { self.alive += 1; self.dead -= inframe; },
+                BinaryCell::Passive => { self.alive -= 1; self.dead += inframe; },
+            }
+            // The grid is no longer known to be stable
+            self.stable = false;
+        }
+    }
+
+    /// A setter method that sets the policy for positions beyond the edges of the grid.
+    /// Returns an error as the grid has no edges.
+    fn setboundary(&mut self, boundary: Boundary) -> Result<()> {
+        Err(Error::Unsupported { automaton: Automaton::name(self), option: format!("boundaries on an unbounded grid (given '{}')", boundary) })
+    }
+
+    /// A method that resizes the frame of the grid to the given dimensions (in cells).
+    fn resize(&mut self, width: usize, height: usize) -> Result<()> {
+        self.grid.resize(width, height);
+        // Recount the dead cells within the resized frame
+        let (alive, dead) = self.grid.population();
+        self.alive = alive;
+        self.dead = dead;
+
+        Ok(())
+    }
+
+    /// A setter method that sets the birth/survival rule of the automaton (ex: "B36/S23").
+    /// Rules with B0 are not supported as they would fill the unbounded grid at once.
+    fn setrule(&mut self, rule: &str) -> Result<()> {
+        let parsed: Rule = rule.parse()?;
+        if parsed.born(0) {
+            return Err(Error::Unsupported {
+                automaton: Automaton::name(self),
+                option: format!("rules with B0 on an unbounded grid (given '{}')", rule),
+            });
+        }
+
+        self.rule = parsed;
+        // The grid is no longer known to be stable
+        self.stable = false;
+
+        Ok(())
+    }
+
+    /// A method that returns the live cells of the grid as a pattern with the rule of the automaton.
+    fn pattern(&self, bounded: bool) -> Result<Pattern> {
+        let mut pattern = Pattern::from_sparse(&self.grid, bounded);
+        pattern.rule = Some(self.rule.to_string());
+        Ok(pattern)
+    }
+}
+
+/// Implementation of the LifeGrid trait for SparseGrid.
+impl LifeGrid for SparseGrid {
+    /// A method that counts the cells of the grid by state.
+    fn population(&self) -> (u32, u32) {
+        SparseGrid::population(self)
+    }
+
+    /// A method that returns the live cells that place the given pattern onto the grid.
+    fn placement(&self, pattern: &Pattern, placement: Placement) -> Vec<GridPoint<BinaryCell>> {
+        SparseGrid::placement(self, pattern, placement)
+    }
+
+    /// A method that places the given pattern onto the grid.
+    fn place(&mut self, pattern: &Pattern, placement: Placement) {
+        SparseGrid::place(self, pattern, placement)
+    }
+}
@@ -1,11 +1,10 @@
-use ggez::graphics;
-use ggez::nalgebra as na;
-
+use crate::{Error, Result};
 use crate::langtonsant::LangtonsAnt;
-use crate::commons::grids::CellGrid;
+use crate::commons::grids::{Boundary, CellGrid, Pattern, Placement};
+use crate::commons::random;
 use crate::commons::cells::BinaryCell;
 use crate::simulation::{SimGrid, Automaton};
-use crate::commons::navigation::{Turmite, Orient, Direction4};
+use crate::commons::navigation::{Turmite, Orient};
 
 
 /// Implementation of the Automaton trait for Langton's Ant with a CellGrid grid,
@@ -13,261 +12,242 @@ impl Automaton for LangtonsAnt<CellGrid<BinaryCell>> {
     /// Defines the type of grid for the automaton.
     type Grid = CellGrid<BinaryCell>;
 
+    /// Defines the type of checkpoint for the automaton.
+    /// Holds the generation, alive and dead counters and the ant.
+    type Checkpoint = (u32, u32, u32, Option<Turmite<Self::Grid>>);
+
     /// A constructor method that creates a null automaton
-    /// ands sets the initial state and cell size parameters.
-    fn new(initialstate: &str, cellsize: f32) -> Self {
+    /// ands sets the initial state and seed parameters.
+    fn new(initialstate: &str, seed: u64) -> Self {
         Self {
-            grid: Self::Grid::new(cellsize),
+            grid: Self::Grid::new(),
             initialstate: initialstate.to_string(),
-            cellsize,
+            seed,
             generation: 0,
+            alive: 0,
+            dead: 0,
             ant: None,
         }
     }
 
-    /// A method that initializes the automaton for the given dimensions.
-    fn initialize(&mut self, dimensions: graphics::Rect) {
-        // Create a new dimensions object for the grid of cells (60 px removed for the banner)
-        let griddimensions = graphics::Rect::new(0.0, 0.0, dimensions.w, dimensions.h - 60.0);
-        // Set the grid dimensions to the grid 
-        self.grid.setdimensions(griddimensions);
-        
+    /// A method that initializes the automaton for the given grid size (in cells).
+    fn initialize(&mut self, width: usize, height: usize) -> Result<()> {
+        // Create the random number generator from the seed
+        let mut rng = random::seeded(self.seed);
+
         // Check the value of the initial state field
         match self.initialstate.as_str() {
-            // Default initial state (random-balanced)
-            "default" => {
+            // Default initial state (empty) or Empty initial state
+            "default" | "empty" => {
                 // Set the initial state string of the automaton
                 self.initialstate = "Empty".to_string();
 
-                // Create a grid of random cells with a balanced ratio of dead and alive cells
-                let randomgrid = CellGrid::<BinaryCell>::generate_empty_grid(self.cellsize, griddimensions);
+                // Create a grid of passive cells
+                let emptygrid = CellGrid::<BinaryCell>::generate_empty_grid(width, height)?;
                 // Set the generated grid to the automaton grid
-                self.grid.setgrid(randomgrid);
+                self.grid.setgrid(emptygrid);
 
-                self.ant = Some(Turmite::new(&self.grid));
+                // Place the ant at a random position on the grid
+                self.ant = Some(Turmite::new(&self.grid, &mut rng)?);
             },
 
             // Invalid initial state
             _ => {
-                // Print an error and exit
-                eprintln!("[error] invalid initial state for 'gameoflife'");
-                std::process::exit(0);
+                // Return an error
+                return Err(Error::InvalidInitialState {
+                    automaton: "langtonsant".to_string(),
+                    state: self.initialstate.clone(),
+                });
             }
         }
+
+        // Count the alive and dead cells of the initial state
+        let (alive, dead) = self.grid.population();
+        self.alive = alive;
+        self.dead = dead;
+
+        Ok(())
     }
 
     /// A method that advances the ant to the next generation.
+    /// The cell under the ant is flipped in place.
     fn advance(&mut self) {
-        // Check if the cell grid exists
-        if self.grid.vector.is_some() {
-            // Clone the ant and check if it is active
-            let mut newant = self.ant.clone().unwrap();
-            if !newant.active {
-                // Return if the ant is inactive
-                return;
+        // Check if there is an active ant on the grid
+        let ant = match &mut self.ant {
+            Some(ant) if ant.active => ant,
+            // Return if there is no ant or the ant is inactive
+            _ => return,
+        };
+
+        // Check if the ant step and automaton generation are in sync
+        if self.generation == ant.step {
+            // Get the cell state of the current cell that the ant is on
+            let (_, _, cell) = ant.position;
+            // Rotate the ant based on the automaton rules
+            ant.orientation = match cell {
+                // If cell is active, turn right
+                BinaryCell::Active => ant.orientation.turn_right(),
+                // If cell is inactive, turn left
+                BinaryCell::Passive => ant.orientation.turn_left(),
+            };
+
+            // Increase the step count of the ant
+            ant.step += 1;
+
+        // If ant and automaton are not in sync
+        } else {
+            // Flip the current cell of the ant
+            let cell = ant.flipcell();
+
+            // Get the position of the ant and update the grid with the new cell state
+            let (x, y, _) = ant.position;
+            self.grid.setcell(x, y, cell);
+            // Update the alive and dead counters for the flipped cell
+            match cell {
+                BinaryCell::Active => { self.alive += 1; self.dead -= 1; },
+                BinaryCell::Passive => { self.alive -= 1; self.dead += 1; },
+            }
+            // Move the ant forward by one unit (following the boundary policy at the grid edge)
+            ant.move_forward(&self.grid);
+
+            // Update the automaton generation and sync with ant step count
+            self.generation += 1;
+        }
+    }
+
+    /// A setter method that sets the cell at the given position of the grid.
+    /// Updates the alive and dead counters if the cell changes state and
+    /// the cell state seen by the ant if the ant is on that cell.
+    fn setcell(&mut self, x: isize, y: isize, cell: BinaryCell) {
+        // Get the current state of the cell (return if out of bounds)
+        let current = match self.grid.getcell(x, y) {
+            Some(current) => current,
+            None => return,
+        };
+
+        // Check if the cell changes state
+        if current != cell && self.grid.setcell(x, y, cell) {
+            // Update the alive and dead counters
+            match cell {
+                BinaryCell::Active => { self.alive += 1; self.dead -= 1; },
+                BinaryCell::Passive => { self.alive -= 1; self.dead += 1; },
             }
 
-            // Check if the ant step and automaton generation are in sync
-            if self.generation == newant.step {
-                // Get the cell state of the current cell that the ant is on
-                let (_, _, cell) = newant.position;
-                // Rotate the ant based on the automaton rules
-                let newdir = match cell {
-                    // If cell is active, turn right
-                    BinaryCell::Active => newant.orientation.turn_right(),
-                    // If cell is inactive, turn left
-                    BinaryCell::Passive => newant.orientation.turn_left(),
-                };
-
-                // Set the new orientation of the ant
-                newant.orientation = newdir;
-                // Increase the step count of the ant
-                newant.step += 1;
-                // Update the automaton ant with the new ant state
-                self.ant = Some(newant);
-
-            // If ant and automaton are not in sync
-            } else {
-                // Create a clone of the cell grid
-                let mut newgrid = self.grid.vector.clone().unwrap();
-                // Flip the current cell of the ant
-                newant.flipcell();
-
-                // Get the cell state and position of the ant
-                let (x, y, cell) = newant.position;
-                // Update the grid clone for that position with the new cell state
-                newgrid[x][y] = cell;
-                // Move the ant forward by one unit (kill turmite if at grid edge)
-                newant.move_forward(&newgrid);
-
-                // Update the automaton ant with the new ant state
-                self.ant = Some(newant);
-                // Update the automaton generation and sync with ant step count
-                self.generation += 1;
-                // Update the automaton grid with the new grid state
-                self.grid.setgrid(newgrid);
+            // Update the position of the ant if it is on the cell
+            if let Some(ant) = &mut self.ant {
+                if ant.position.0 == x && ant.position.1 == y {
+                    ant.position = (x, y, cell);
+                }
             }
         }
     }
 
-    /// A method that returns the state of the automaton as a string.
-    /// Format: "Generation: {} | Alive: {} | Dead: {}"
-    fn state(&self) -> String {
-        format!("Generation: {}", self.generation)
+    /// A getter method that returns the grid of the automaton.
+    fn grid(&self) -> &Self::Grid {
+        &self.grid
     }
 
-    /// A method that returns the name of the automaton as a string.
-    /// Format: "Langton's Ant"
-    fn name(&self) -> String {
-        "Langton's Ant".to_string()
+    /// A setter method that sets the policy for positions beyond the edges of the grid.
+    fn setboundary(&mut self, boundary: Boundary) -> Result<()> {
+        self.grid.setboundary(boundary);
+        Ok(())
     }
 
-    /// A method that returns the name of the automaton as a string 
-    /// along with its initial state and grid type.
-    /// Format: "Langton's Ant | Grid | {}"
-    fn fullname(&self) -> String {
-        format!("Langton's Ant | Grid | {}", self.initialstate)
+    /// A method that resizes the grid to the given dimensions (in cells). The cells beyond
+    /// the new edges are dropped and the new cells are dead. An ant that was beyond the
+    /// new edges is moved onto the nearest cell of the grid.
+    fn resize(&mut self, width: usize, height: usize) -> Result<()> {
+        self.grid.resize(width, height, BinaryCell::Passive);
+        // Keep the ant on the grid
+        if let Some(ant) = &mut self.ant {
+            ant.confine(&self.grid);
+        }
+
+        // Recount the alive and dead cells of the resized grid
+        let (alive, dead) = self.grid.population();
+        self.alive = alive;
+        self.dead = dead;
+
+        Ok(())
     }
-}
 
-// Implementation of the Drawable trait for GameOfLife with a CellGrid grid,
-impl graphics::Drawable for LangtonsAnt<CellGrid<BinaryCell>> {
+    /// A method that places the given pattern onto the grid under the ant.
+    /// The initial state of the automaton is set to the name of the pattern.
+    fn place(&mut self, pattern: &Pattern, placement: Placement) -> Result<()> {
+        // Set the cells of the pattern (updates the counters and the cell under the ant)
+        for (x, y, cell) in self.grid.placement(pattern, placement) {
+            self.setcell(x, y, cell);
+        }
 
-    // A method that returns the dimensions of the automaton
-    fn dimensions(&self, _ctx: &mut ggez::Context) -> Option<graphics::Rect> {
-        // Get the grid dimesions and add the banner height
-        if let Some(dimensions) = &self.grid.dimensions {
-            Some(graphics::Rect::new(0.0, 0.0, dimensions.w, dimensions.h + 60.0))
-        } else {None}     
+        // Set the initial state string of the automaton
+        self.initialstate = format!("Pattern [{}]", pattern.name.as_deref().unwrap_or("Unnamed"));
+        Ok(())
     }
 
-    // A method that returns the graphics blending mode of the automaton grid
-    fn blend_mode(&self) -> Option<graphics::BlendMode> {
-        Some(graphics::BlendMode::Add)
+    /// A method that returns the live cells of the grid as a pattern.
+    fn pattern(&self, bounded: bool) -> Result<Pattern> {
+        Ok(Pattern::from_grid(&self.grid, bounded))
     }
 
-    // A method that set the graphics blend mode of the automaton grid (currently does nothing)
-    fn set_blend_mode(&mut self, _: Option<graphics::BlendMode>) {}
-
-    // A method that renders the automaton grid and state and returns a GameResult
-    fn draw(&self, ctx: &mut ggez::Context, param: graphics::DrawParam) -> ggez::GameResult<()> {
-        
-        // Create a new graphic mesh builder
-        let mut mb = graphics::MeshBuilder::new();
-
-        // Iterate through each cell in the grid
-        for (x, y, cell) in self.grid.clone() {
-
-            // Create the bounds of the cell
-            let cellbounds = graphics::Rect::new(
-                (x as f32) * self.cellsize,
-                (y as f32) * self.cellsize,
-                self.cellsize,
-                self.cellsize,
-            );
-
-            // Add the cell fill to the mesh builder
-            mb.rectangle(
-                graphics::DrawMode::Fill(graphics::FillOptions::default()),
-                cellbounds,
-                // Set the cell color based on cell state
-                match cell {
-                    BinaryCell::Passive => [0.0, 0.0, 0.0, 1.0].into(),
-                    BinaryCell::Active => [1.0, 1.0, 1.0, 1.0].into(),
-                },
-            )
-            // Add the cell boundary to the mesh builder
-            .rectangle(
-                graphics::DrawMode::Stroke(graphics::StrokeOptions::default()),
-                cellbounds,
-                [1.0, 1.0, 1.0, 0.25].into(),
-            );
-        }
+    /// A method that returns a checkpoint of the current state of the automaton.
+    fn checkpoint(&self) -> Self::Checkpoint {
+        (self.generation, self.alive, self.dead, self.ant.clone())
+    }
 
-        // Check if the ant is initialized
-        if let Some(ant) = &self.ant {
-            // Get the position of the ant
-            let (x, y, _) = ant.position;
-            // Create the centroid of the ant sprite based on the position
-            let centroid = na::Point2::new((x as f32 * self.cellsize) + self.cellsize/2.0, (y as f32 * self.cellsize) + self.cellsize/2.0);
-            // Define a metric of distance between the ant sprite vertices
-            let unitdist = self.cellsize/4.0;
-
-            // Get the vertices of the ants sprite based on its orientation.
-            // The ant sprite is a triangle pointing in the direction of its orientation
-            let points = match ant.orientation {
-                Direction4::North => {[
-                    na::Point2::new(centroid.x, centroid.y + unitdist), 
-                    na::Point2::new(centroid.x + unitdist, centroid.y - unitdist), 
-                    na::Point2::new(centroid.x - unitdist, centroid.y - unitdist)
-                ]},
-                Direction4::East => {[
-                    na::Point2::new(centroid.x + unitdist, centroid.y),
-                    na::Point2::new(centroid.x - unitdist, centroid.y - unitdist),
-                    na::Point2::new(centroid.x - unitdist, centroid.y + unitdist)
-                ]},
-                Direction4::South => {[
-                    na::Point2::new(centroid.x, centroid.y - unitdist),
-                    na::Point2::new(centroid.x - unitdist, centroid.y + unitdist),
-                    na::Point2::new(centroid.x + unitdist, centroid.y + unitdist)
-                ]},
-                Direction4::West => {[
-                    na::Point2::new(centroid.x - unitdist, centroid.y),
-                    na::Point2::new(centroid.x + unitdist, centroid.y + unitdist),
-                    na::Point2::new(centroid.x + unitdist, centroid.y - unitdist)
-                ]},
-            };
+    /// A method that restores the automaton to the given checkpoint.
+    fn restore(&mut self, checkpoint: Self::Checkpoint) {
+        let (generation, alive, dead, ant) = checkpoint;
+        self.generation = generation;
+        self.alive = alive;
+        self.dead = dead;
+        self.ant = ant;
+    }
 
-            // Set the color of the ant based on if its alive
-            let color;
-            if ant.active {
-                // Red color if ant is alive
-                color = [1.0, 0.0, 0.0, 1.0];
-            } else {
-                // Blue color is ant is dead
-                color = [0.0, 1.0, 0.0, 1.0];
-            }
+    /// A getter method that returns the current generation of the automaton.
+    fn generation(&self) -> u32 {
+        self.generation
+    }
 
-            // Construct the triangle polygon from the sprite vertices
-            mb.polygon(
-                graphics::DrawMode::Fill(graphics::FillOptions::default()), 
-                &points, 
-                color.into()
-            )?;
-        }
-        
-        // Build and Draw the mesh
-        mb.build(ctx)?.draw(ctx, param)?;
-
-        // Declare a variable for the font size
-        let font_size = 18.0;
-
-        // Create the text graphics for the banner
-        let mut name_text = graphics::Text::new(self.fullname());
-        let mut state_text = graphics::Text::new(self.state());
-
-        // Set the font styling for the text graphics
-        state_text.set_font(graphics::Font::default(), graphics::Scale::uniform(font_size));
-        name_text.set_font(graphics::Font::default(), graphics::Scale::uniform(font_size));
-
-        // Chekc the grid dimesions
-        if let Some(dimensions) = &self.grid.dimensions {
-            // Calculate the spacing between banner elements.
-            // Assumes 2 units of spacing above the name text and below the state text
-            // and 1 unit of spacing between the name and state text.
-            let spacing = (60.0 - (font_size * 2.0)) / 5.0;
-
-            // Calculate the position of the name text
-            let name_offset = dimensions.h + (spacing * 2.0);
-            // Calculate the position of the state text
-            let state_offset = dimensions.h + (spacing * 3.0) + font_size;
-
-            // Draw the banner text graphics
-            name_text.draw(ctx, (na::Point2::new(param.dest.x + 10.0, param.dest.y + name_offset),).into())?;
-            state_text.draw(ctx, (na::Point2::new(param.dest.x + 10.0, param.dest.y + state_offset),).into())?;
+    /// A getter method that returns the seed of the automaton.
+    fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// A getter method that returns the number of alive cells on the grid.
+    fn alive(&self) -> u32 {
+        self.alive
+    }
+
+    /// A getter method that returns the number of dead cells on the grid.
+    fn dead(&self) -> u32 {
+        self.dead
+    }
+
+    /// A method that returns whether langton's ant has halted.
+    /// The ant halts once it dies at the edge of the grid.
+    fn halted(&self) -> bool {
+        match &self.ant {
+            Some(ant) => !ant.active,
+            None => true,
         }
+    }
 
-        // Return GameResult::Ok
-        Ok(())
+    /// A method that returns the state of the automaton as a string.
+    /// Format: "Generation: {} | Alive: {} | Dead: {}"
+    fn state(&self) -> String {
+        format!("Generation: {} | Alive: {} | Dead: {}", self.generation, self.alive, self.dead)
+    }
+
+    /// A method that returns the name of the automaton as a string.
+    /// Format: "Langton's Ant"
+    fn name(&self) -> String {
+        "Langton's Ant".to_string()
+    }
+
+    /// A method that returns the name of the automaton as a string 
+    /// along with its initial state and grid type.
+    /// Format: "Langton's Ant | Grid | {}"
+    fn fullname(&self) -> String {
+        format!("Langton's Ant | {} | {}", self.grid.name(), self.initialstate)
     }
 }
@@ -1,19 +1,43 @@
 pub mod cellgrid;
+pub mod sparsegrid;
+#[cfg(feature = "graphics")]
+mod render;
+
+use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
 
 use crate::simulation::SimGrid;
 use crate::commons::navigation::Turmite;
 
 /// A struct that represents the automaton 
 /// for Langton's Ant on a generic grid.
+#[derive(Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "T: Serialize, T::Orientation: Serialize, T::Cell: Serialize",
+    deserialize = "T: DeserializeOwned, T::Orientation: DeserializeOwned, T::Cell: DeserializeOwned",
+))]
 pub struct LangtonsAnt<T> where T: SimGrid {
     /// Represents the automaton grid.
     grid: T,    
-    /// Represents the size of an individual cell.
-    cellsize: f32,
     /// Represents the intial state of the automaton.
     initialstate: String,
+    /// Represents the seed of the automaton's random number generator.
+    seed: u64,
     /// Represents the number of times the grid has been updated
     generation: u32,
+    /// Represents the number of cells that are alive
+    alive: u32,
+    /// Represents the number of cells that are dead
+    dead: u32,
     /// Represents langton's ant on the grid
     ant: Option<Turmite<T>>,
 }
+
+/// Implementation of getters for LangtonsAnt
+impl<T: SimGrid> LangtonsAnt<T> {
+    /// A getter method that returns langton's ant on the grid.
+    /// Returns None if the automaton has not been initialized.
+    pub fn ant(&self) -> Option<&Turmite<T>> {
+        self.ant.as_ref()
+    }
+}
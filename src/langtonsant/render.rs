@@ -0,0 +1,98 @@
+use ggez::graphics;
+use ggez::GameResult;
+use ggez::nalgebra as na;
+
+use crate::langtonsant::LangtonsAnt;
+use crate::simulation::{Renderable, SimGrid};
+use crate::commons::grids::{CellGrid, SparseGrid};
+use crate::commons::cells::BinaryCell;
+use crate::commons::navigation::{Direction4, Turmite};
+
+// Implementation of the Renderable trait for Langton's Ant with a CellGrid grid,
+impl Renderable for LangtonsAnt<CellGrid<BinaryCell>> {
+    // A method that renders the automaton grid and the ant into the mesh builder
+    fn render(&self, mb: &mut graphics::MeshBuilder, cellsize: f32, visible: graphics::Rect) -> GameResult {
+        // Add the visible cells of the grid to the mesh builder
+        self.grid.render(mb, cellsize, visible);
+
+        // Add the ant to the mesh builder if it is initialized
+        if let Some(ant) = &self.ant {
+            render_ant(ant, mb, cellsize)?;
+        }
+
+        // Return GameResult::Ok
+        Ok(())
+    }
+}
+
+// Implementation of the Renderable trait for Langton's Ant with a SparseGrid grid,
+impl Renderable for LangtonsAnt<SparseGrid> {
+    // A method that renders the automaton grid and the ant into the mesh builder
+    fn render(&self, mb: &mut graphics::MeshBuilder, cellsize: f32, visible: graphics::Rect) -> GameResult {
+        // Add the visible live cells of the grid to the mesh builder
+        self.grid.render(mb, cellsize, visible);
+
+        // Add the ant to the mesh builder if it is initialized
+        if let Some(ant) = &self.ant {
+            render_ant(ant, mb, cellsize)?;
+        }
+
+        // Return GameResult::Ok
+        Ok(())
+    }
+
+    // A method that returns the region covered by the frame and the live cells of the grid
+    fn extent(&self, cellsize: f32) -> graphics::Rect {
+        self.grid.extent(cellsize)
+    }
+}
+
+// A function that adds the sprite of the given ant to the mesh builder.
+// The ant sprite is a triangle pointing in the direction of its orientation.
+fn render_ant<T: SimGrid<Orientation = Direction4>>(ant: &Turmite<T>, mb: &mut graphics::MeshBuilder, cellsize: f32) -> GameResult {
+    // Get the position of the ant
+    let (x, y, _) = ant.position;
+    // Create the centroid of the ant sprite based on the position
+    let centroid = na::Point2::new((x as f32 * cellsize) + cellsize/2.0, (y as f32 * cellsize) + cellsize/2.0);
+    // Define a metric of distance between the ant sprite vertices
+    let unitdist = cellsize/4.0;
+
+    // Get the vertices of the ants sprite based on its orientation.
+    // The ant sprite is a triangle pointing in the direction of its orientation
+    let points = match ant.orientation {
+        Direction4::North => {[
+            na::Point2::new(centroid.x, centroid.y + unitdist),
+            na::Point2::new(centroid.x + unitdist, centroid.y - unitdist),
+            na::Point2::new(centroid.x - unitdist, centroid.y - unitdist)
+        ]},
+        Direction4::East => {[
+            na::Point2::new(centroid.x + unitdist, centroid.y),
+            na::Point2::new(centroid.x - unitdist, centroid.y - unitdist),
+            na::Point2::new(centroid.x - unitdist, centroid.y + unitdist)
+        ]},
+        Direction4::South => {[
+            na::Point2::new(centroid.x, centroid.y - unitdist),
+            na::Point2::new(centroid.x - unitdist, centroid.y + unitdist),
+            na::Point2::new(centroid.x + unitdist, centroid.y + unitdist)
+        ]},
+        Direction4::West => {[
+            na::Point2::new(centroid.x - unitdist, centroid.y),
+            na::Point2::new(centroid.x + unitdist, centroid.y + unitdist),
+            na::Point2::new(centroid.x + unitdist, centroid.y - unitdist)
+        ]},
+    };
+
+    // Set the color of the ant based on if its alive.
+    // Red color if ant is alive, green color if ant is dead
+    let color = if ant.active { [1.0, 0.0, 0.0, 1.0] } else { [0.0, 1.0, 0.0, 1.0] };
+
+    // Construct the triangle polygon from the sprite vertices
+    mb.polygon(
+        graphics::DrawMode::Fill(graphics::FillOptions::default()),
+        &points,
+        color.into()
+    )?;
+
+    // Return GameResult::Ok
+    Ok(())
+}
@@ -0,0 +1,246 @@
+use crate::{Error, Result};
+use crate::langtonsant::LangtonsAnt;
+use crate::commons::grids::{Boundary, CellGrid, Pattern, Placement, SparseGrid};
+use crate::commons::random;
+use crate::commons::cells::BinaryCell;
+use crate::simulation::{SimGrid, Automaton};
+use crate::commons::navigation::{Turmite, Orient};
+
+
+/// Implementation of the Automaton trait for Langton's Ant with a SparseGrid grid,
+impl Automaton for LangtonsAnt<SparseGrid> {
+    /// Defines the type of grid for the automaton.
+    type Grid = SparseGrid;
+
+    /// Defines the type of checkpoint for the automaton.
+    /// Holds the generation, alive and dead counters and the ant.
+    type Checkpoint = (u32, u32, u32, Option<Turmite<Self::Grid>>);
+
+    /// A constructor method that creates a null automaton
+    /// ands sets the initial state and seed parameters.
+    fn new(initialstate: &str, seed: u64) -> Self {
+        Self {
+            grid: Self::Grid::new(),
+            initialstate: initialstate.to_string(),
+            seed,
+            generation: 0,
+            alive: 0,
+            dead: 0,
+            ant: None,
+        }
+    }
+
+    /// A method that initializes the automaton for the given size of the frame of the grid (in cells).
+    fn initialize(&mut self, width: usize, height: usize) -> Result<()> {
+        // Create the random number generator from the seed
+        let mut rng = random::seeded(self.seed);
+
+        // Check the value of the initial state field
+        match self.initialstate.as_str() {
+            // Default initial state (empty) or Empty initial state
+            "default" | "empty" => {
+                // Set the initial state string of the automaton
+                self.initialstate = "Empty".to_string();
+
+                // Create a frame of passive cells
+                let emptygrid = CellGrid::<BinaryCell>::generate_empty_grid(width, height)?;
+                // Set the generated frame to the automaton grid
+                self.grid.setgrid(emptygrid);
+
+                // Place the ant at a random position within the frame
+                self.ant = Some(Turmite::new(&self.grid, &mut rng)?);
+            },
+
+            // Invalid initial state
+            _ => {
+                // Return an error
+                return Err(Error::InvalidInitialState {
+                    automaton: "langtonsant".to_string(),
+                    state: self.initialstate.clone(),
+                });
+            }
+        }
+
+        // Count the alive and dead cells of the initial state
+        let (alive, dead) = self.grid.population();
+        self.alive = alive;
+        self.dead = dead;
+
+        Ok(())
+    }
+
+    /// A method that advances the ant to the next generation.
+    /// The cell under the ant is flipped in place.
+    fn advance(&mut self) {
+        // Check if there is an ant on the grid
+        let ant = match &mut self.ant {
+            Some(ant) => ant,
+            // Return if there is no ant
+            None => return,
+        };
+
+        // Check if the ant step and automaton generation are in sync
+        if self.generation == ant.step {
+            // Get the cell state of the current cell that the ant is on
+            let (_, _, cell) = ant.position;
+            // Rotate the ant based on the automaton rules
+            ant.orientation = match cell {
+                // If cell is active, turn right
+                BinaryCell::Active => ant.orientation.turn_right(),
+                // If cell is inactive, turn left
+                BinaryCell::Passive => ant.orientation.turn_left(),
+            };
+
+            // Increase the step count of the ant
+            ant.step += 1;
+
+        // If ant and automaton are not in sync
+        } else {
+            // Flip the current cell of the ant
+            let cell = ant.flipcell();
+
+            // Get the position of the ant and update the grid with the new cell state
+            let (x, y, _) = ant.position;
+            let (cx, cy) = (x as i64, y as i64);
+            self.grid.set(cx, cy, cell);
+            // Update the alive counter (and the dead counter within the frame) for the flipped cell
+            let inframe = self.grid.inframe(cx, cy) as u32;
+            match cell {
+                BinaryCell::Active => { self.alive += 1; self.dead -= inframe; },
+                BinaryCell::Passive => { self.alive -= 1; self.dead += inframe; },
+            }
+            // Move the ant forward by one unit
+            ant.move_forward(&self.grid);
+
+            // Update the automaton generation and sync with ant step count
+            self.generation += 1;
+        }
+    }
+
+    /// A setter method that sets the cell at the given position of the grid.
+    /// Updates the alive and dead counters if the cell changes state and
+    /// the cell state seen by the ant if the ant is on that cell.
+    fn setcell(&mut self, x: isize, y: isize, cell: BinaryCell) {
+        // Check if the cell changes state
+        let (cx, cy) = (x as i64, y as i64);
+        if self.grid.get(cx, cy) != cell {
+            self.grid.set(cx, cy, cell);
+
+            // Update the alive counter (and the dead counter within the frame)
+            let inframe = self.grid.inframe(cx, cy) as u32;
+            match cell {
+                BinaryCell::Active => { self.alive += 1; self.dead -= inframe; },
+                BinaryCell::Passive => { self.alive -= 1; self.dead += inframe; },
+            }
+
+            // Update the position of the ant if it is on the cell
+            if let Some(ant) = &mut self.ant {
+                if ant.position.0 == x && ant.position.1 == y {
+                    ant.position = (x, y, cell);
+                }
+            }
+        }
+    }
+
+    /// A getter method that returns the grid of the automaton.
+    fn grid(&self) -> &Self::Grid {
+        &self.grid
+    }
+
+    /// A setter method that sets the policy for positions beyond the edges of the grid.
+    /// Returns an error as the grid has no edges.
+    fn setboundary(&mut self, boundary: Boundary) -> Result<()> {
+        Err(Error::Unsupported { automaton: self.name(), option: format!("boundaries on an unbounded grid (given '{}')", boundary) })
+    }
+
+    /// A method that resizes the frame of the grid to the given dimensions (in cells).
+    fn resize(&mut self, width: usize, height: usize) -> Result<()> {
+        self.grid.resize(width, height);
+        // Recount the dead cells within the resized frame
+        let (alive, dead) = self.grid.population();
+        self.alive = alive;
+        self.dead = dead;
+
+        Ok(())
+    }
+
+    /// A method that places the given pattern onto the grid under the ant.
+    /// The initial state of the automaton is set to the name of the pattern.
+    fn place(&mut self, pattern: &Pattern, placement: Placement) -> Result<()> {
+        // Set the cells of the pattern (updates the counters and the cell under the ant)
+        for (x, y, cell) in self.grid.placement(pattern, placement) {
+            self.setcell(x, y, cell);
+        }
+
+        // Set the initial state string of the automaton
+        self.initialstate = format!("Pattern [{}]", pattern.name.as_deref().unwrap_or("Unnamed"));
+        Ok(())
+    }
+
+    /// A method that returns the live cells of the grid as a pattern.
+    fn pattern(&self, bounded: bool) -> Result<Pattern> {
+        Ok(Pattern::from_sparse(&self.grid, bounded))
+    }
+
+    /// A method that returns a checkpoint of the current state of the automaton.
+    fn checkpoint(&self) -> Self::Checkpoint {
+        (self.generation, self.alive, self.dead, self.ant.clone())
+    }
+
+    /// A method that restores the automaton to the given checkpoint.
+    fn restore(&mut self, checkpoint: Self::Checkpoint) {
+        let (generation, alive, dead, ant) = checkpoint;
+        self.generation = generation;
+        self.alive = alive;
+        self.dead = dead;
+        self.ant = ant;
+    }
+
+    /// A getter method that returns the current generation of the automaton.
+    fn generation(&self) -> u32 {
+        self.generation
+    }
+
+    /// A getter method that returns the seed of the automaton.
+    fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// A getter method that returns the number of alive cells on the grid.
+    fn alive(&self) -> u32 {
+        self.alive
+    }
+
+    /// A getter method that returns the number of dead cells within the frame of the grid.
+    fn dead(&self) -> u32 {
+        self.dead
+    }
+
+    /// A method that returns whether langton's ant has halted.
+    /// The ant only halts if there is no ant.
+    fn halted(&self) -> bool {
+        match &self.ant {
+            Some(ant) => !ant.active,
+            None => true,
+        }
+    }
+
+    /// A method that returns the state of the automaton as a string.
+    /// Format: "Generation: {} | Alive: {} | Dead: {}"
+    fn state(&self) -> String {
+        format!("Generation: {} | Alive: {} | Dead: {}", self.generation, self.alive, self.dead)
+    }
+
+    /// A method that returns the name of the automaton as a string.
+    /// Format: "Langton's Ant"
+    fn name(&self) -> String {
+        "Langton's Ant".to_string()
+    }
+
+    /// A method that returns the name of the automaton as a string
+    /// along with its initial state and grid type.
+    /// Format: "Langton's Ant | Sparse Grid | {}"
+    fn fullname(&self) -> String {
+        format!("Langton's Ant | {} | {}", self.grid.name(), self.initialstate)
+    }
+}
@@ -1,4 +1,7 @@
+pub mod error;
 pub mod commons;
 pub mod simulation;
 pub mod gameoflife;
-pub mod langtonsant;
\ No newline at end of file
+pub mod langtonsant;
+
+pub use crate::error::{Error, Result};
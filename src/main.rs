@@ -1,15 +1,31 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 use clap::{Arg, App};
 
-use automata::simulation::Automaton;
-use automata::simulation::Simulation;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 
-fn main() -> ggez::GameResult {
+use automata::simulation::Registry;
+use automata::simulation::Runner;
+use automata::simulation::simregistry::Entry;
+use automata::commons::bands;
+use automata::commons::grids::{Boundary, Pattern, Placement};
+
+fn main() {
     // Set the version information
     let version = "0.5.0";
     // Set the author information
     let author = "Manish Meganathan <meganthan.manish@gmail.com>";
 
+    // Create the registry of automata that can be simulated
+    let registry = Registry::builtin();
+    // Build the help text and valid values of the automaton argument from the registry
+    let clinames = registry.clinames();
+    let backends = registry.backends();
+    let automatonhelp = format!("Automaton to Run. One of {}. Use --list for details.", clinames.iter()
+        .map(|name| format!("'{}'", name))
+        .collect::<Vec<String>>()
+        .join(", "));
+
     // Create a clap App and parse the arguments
     let matches = App::new("Automata")
         .version(version)
@@ -19,7 +35,19 @@ fn main() -> ggez::GameResult {
         .arg(Arg::with_name("GRID")
             .short("g")    
             .long("grid")
-            .help("Set the grid size").default_value("600x600"))
+            .help("Set the grid size (in pixels, the grid holds the cells that fit in it)").default_value("600x600"))
+        // Argument for grid size in cells
+        .arg(Arg::with_name("CELLS")
+            .long("cells")
+            .takes_value(true)
+            .conflicts_with("GRID")
+            .help("Set the grid size in cells (the grid size in pixels is derived from the cell size)"))
+        // Argument for window size
+        .arg(Arg::with_name("WINDOW")
+            .short("w")
+            .long("window")
+            .takes_value(true)
+            .help("Set the window size (defaults to the grid size)"))
         // Argument for cell size
         .arg(Arg::with_name("CELL")
             .short("c")
@@ -30,14 +58,110 @@ fn main() -> ggez::GameResult {
             .short("f")
             .long("fps")
             .help("Set the simulation FPS").default_value("0"))
+        // Argument for the random seed
+        .arg(Arg::with_name("SEED")
+            .short("s")
+            .long("seed")
+            .takes_value(true)
+            .help("Set the random seed for a reproducible run (printed if not set)"))
+        // Argument for the grid backend
+        .arg(Arg::with_name("BACKEND")
+            .long("backend")
+            .possible_values(&backends)
+            .help("Set the grid backend of the automaton. 'grid' is a bounded grid, 'sparse' is an unbounded grid of live cells").default_value("grid"))
+        // Argument for the initial state
+        .arg(Arg::with_name("INIT")
+            .short("i")
+            .long("init")
+            .takes_value(true)
+            .conflicts_with("RESUME")
+            .help("Set the initial state of the automaton (see --list for the states of each automaton) [default: default]"))
+        // Argument for the boundary policy
+        .arg(Arg::with_name("BOUNDARY")
+            .short("b")
+            .long("boundary")
+            .takes_value(true)
+            .possible_values(&Boundary::NAMES)
+            .help("Set the behaviour at the edges of the grid [default: dead, or that of a resumed snapshot]"))
+        // Argument for the rule
+        .arg(Arg::with_name("RULE")
+            .short("r")
+            .long("rule")
+            .takes_value(true)
+            .help("Set the birth/survival rule of a Life-like automaton. Ex. 'B3/S23', 'B36/S23' [default: B3/S23]"))
+        // Argument for the pattern file
+        .arg(Arg::with_name("PATTERN")
+            .short("p")
+            .long("pattern")
+            .takes_value(true)
+            .conflicts_with("RESUME")
+            .help("Place a pattern file (.rle, .cells or .lif) onto the grid. The initial state defaults to 'empty' if supported"))
+        // Argument for the position of the pattern
+        .arg(Arg::with_name("AT")
+            .long("at")
+            .takes_value(true)
+            .help("Set the position of the top left corner of the pattern or built-in pattern as XxY (in cells) [default: centred]"))
+        // Argument for the export path
+        .arg(Arg::with_name("EXPORT")
+            .long("export")
+            .takes_value(true)
+            .help("Set the path that patterns (.rle, .cells or .lif) are exported to (F6 in the window or at the end of a headless run)"))
+        // Argument for exporting the full grid
+        .arg(Arg::with_name("EXPORTFULL")
+            .long("export-full")
+            .help("Export the full grid instead of the bounding box of the live cells"))
+        // Argument for the snapshot path
+        .arg(Arg::with_name("SNAPSHOT")
+            .long("snapshot")
+            .takes_value(true)
+            .help("Set the path that snapshots are saved to (F5 in the window or at the end of a headless run)"))
+        // Argument for resuming from a snapshot
+        .arg(Arg::with_name("RESUME")
+            .long("resume")
+            .takes_value(true)
+            .help("Resume the automaton from a snapshot file"))
+        // Argument for the history size
+        .arg(Arg::with_name("HISTORY")
+            .long("history")
+            .help("Set the number of generations that can be rewound in the window (0 disables it)").default_value("100"))
+        // Argument for the number of generations to run headless
+        .arg(Arg::with_name("GENERATIONS")
+            .short("n")
+            .long("generations")
+            .takes_value(true)
+            .help("Run headless for the given number of generations and print the stats of each"))
+        // Argument for the number of generations per step
+        .arg(Arg::with_name("STRIDE")
+            .long("stride")
+            .help("Set the number of generations to advance at a time. Headless runs print the stats after each stride. The hashlife backend advances a stride in a few large steps").default_value("1"))
+        // Argument for the number of threads that step the grid
+        .arg(Arg::with_name("THREADS")
+            .long("threads")
+            .takes_value(true)
+            .help("Set the number of threads that step the grid in parallel row bands (defaults to the number of cores). Requires the 'parallel' feature"))
+        // Argument for stopping a headless run once the automaton halts
+        .arg(Arg::with_name("UNTILHALT")
+            .long("until-halt")
+            .help("Run headless until the automaton halts (stable grid or dead ant)"))
+        // Argument for the output format of a headless run
+        .arg(Arg::with_name("FORMAT")
+            .long("format")
+            .possible_values(&["csv", "json"])
+            .help("Set the output format of a headless run").default_value("csv"))
         .arg(Arg::with_name("VERSION")
             .short("v")
             .long("version")
             .help("Prints application version"))
+        // Argument for listing the available automata
+        .arg(Arg::with_name("LIST")
+            .long("list")
+            .help("Lists the available automata and their initial states"))
         // Argument for automaton to run
         .arg(Arg::with_name("AUTOMATON")
-            .help("Automaton to Run. Ex. 'gameoflife', 'langtonsant', etc. Refer to README for all options.")
-            .required(true)
+            .help(automatonhelp.as_str())
+            .possible_values(&clinames)
+            .hide_possible_values(true)
+            .required_unless("LIST")
             .index(1))
         // Retrieve the argument matches
         .get_matches();
@@ -54,6 +178,23 @@ fn main() -> ggez::GameResult {
         }
     }
 
+    // Check if the user has requested the list of automata
+    if matches.is_present("LIST") {
+        // Print the information of each automaton (with its backends) and exit
+        for cliname in registry.clinames() {
+            let backends = registry.backends_of(cliname);
+            let entry = match registry.get(cliname, backends[0]) {
+                Some(entry) => entry,
+                None => continue,
+            };
+            println!("{:<16}{}", entry.info.cliname, entry.info.name);
+            println!("{:<16}{}", "", entry.info.description);
+            println!("{:<16}Backends: {}", "", backends.join(", "));
+            println!("{:<16}Initial States: {}", "", entry.info.initialstates.join(", "));
+        }
+        std::process::exit(0);
+    }
+
     // Declare a bunch of variables
     let grid_w;
     let grid_h;
@@ -62,49 +203,17 @@ fn main() -> ggez::GameResult {
 
     // Check for grid size argument
     match matches.value_of("GRID") {
-        // If grid dimensions are set
+        // If grid dimensions are set, parse them
         Some(grid) => {
-            // Split dimensions string
-            let dimensions = grid.split("x").collect::<Vec<&str>>();
-            // Check number of dimension values
-            match dimensions.len() {
-                // Only 2 values expected (width and height)
-                2 => {
-                    // Parse the width into a float
-                    match dimensions[0].parse::<f32>() {
-                        // If the parse fails, print an error and exit
-                        Err(_) => {
-                            eprintln!("[error] invalid grid dimensions. width must be a float");
-                            std::process::exit(0);
-                        },
-                        // If it parses, set the width
-                        Ok(w) => grid_w = w
-                    }
-                    
-                    // Parse the height into a float
-                    match dimensions[1].parse::<f32>() {
-                        // If the parse fails, print an error and exit
-                        Err(_) => {
-                            eprintln!("[error] invalid grid dimensions. height must be a float");
-                            std::process::exit(0);
-                        },
-                        // If it parses, set the height
-                        Ok(h) => grid_h = h
-                    }
-                },
-                // Invalid dimension format
-                _ => {
-                    // Print an error and exit
-                    eprintln!("[error] invalid grid dimensions. must be in WIDTHxHEIGHT format.");
-                    std::process::exit(0);
-                }
-            }
+            let (w, h) = parse_dimensions(grid, "grid");
+            grid_w = w;
+            grid_h = h;
         },
         // If grid dimensions are not set
         None => {
             // Print an error and exit
             eprintln!("[error] missing grid dimensions.");
-            std::process::exit(0);
+            std::process::exit(1);
         }
     }
 
@@ -117,7 +226,7 @@ fn main() -> ggez::GameResult {
                 // If the parse fails, print an error and exit
                 Err(_) => {
                     eprintln!("[error] invalid cell dimensions. cell size must be a float");
-                    std::process::exit(0);
+                    std::process::exit(1);
                 },
                 // If it parses, set the cell size
                 Ok(c) => cell_size = c
@@ -127,10 +236,23 @@ fn main() -> ggez::GameResult {
         None => {
             // Print an error and exit
             eprintln!("[error] missing cell dimensions.");
-            std::process::exit(0);
+            std::process::exit(1);
         }
     }
 
+    // Calculate the grid size (in cells). The grid size in cells is used as is if
+    // set, otherwise the grid holds the cells that fit in the grid size (pixels).
+    let (grid_cols, grid_rows) = match matches.value_of("CELLS") {
+        Some(cells) => parse_cells(cells),
+        None => ((grid_w / cell_size) as usize, (grid_h / cell_size) as usize),
+    };
+
+    // Check for window size argument (defaults to the grid size in pixels)
+    let (window_w, window_h) = match matches.value_of("WINDOW") {
+        Some(window) => parse_dimensions(window, "window"),
+        None => (grid_cols as f32 * cell_size, grid_rows as f32 * cell_size),
+    };
+
     // Check for simulation FPS argument
     match matches.value_of("FPS") {
         // If FPS is set
@@ -140,7 +262,7 @@ fn main() -> ggez::GameResult {
                 // If the parse fails, print an error and exit
                 Err(_) => {
                     eprintln!("[error] invalid simulation FPS. fps must be an int");
-                    std::process::exit(0);
+                    std::process::exit(1);
                 },
                 // If it parses, set the FPS
                 Ok(f) => fps = f
@@ -150,75 +272,437 @@ fn main() -> ggez::GameResult {
         None => {
             // Print an error and exit
             eprintln!("[error] missing cell dimensions.");
-            std::process::exit(0);
+            std::process::exit(1);
         }
     }
     
-    // Automaton Entity Imports
-    use automata::commons::grids::CellGrid;
-    use automata::commons::cells::BinaryCell;
+    // Check for the number of generations argument
+    let generations = match matches.value_of("GENERATIONS") {
+        // If generations are not set
+        None => None,
+        // If generations are set
+        Some(gens) => {
+            // Parse the generations into an integer
+            match gens.parse::<u32>() {
+                // If the parse fails, print an error and exit
+                Err(_) => {
+                    eprintln!("[error] invalid number of generations. generations must be an int");
+                    std::process::exit(1);
+                },
+                // If it parses, set the generations
+                Ok(g) => Some(g)
+            }
+        }
+    };
+
+    // Check for the stride argument
+    let stride = match matches.value_of("STRIDE").unwrap_or("1").parse::<u32>() {
+        // If the parse fails or the stride is 0, print an error and exit
+        Err(_) | Ok(0) => {
+            eprintln!("[error] invalid stride. stride must be a positive int");
+            std::process::exit(1);
+        },
+        // If it parses, set the stride
+        Ok(s) => s
+    };
+
+    // Check for the threads argument
+    if let Some(threads) = matches.value_of("THREADS") {
+        match threads.parse::<usize>() {
+            // If the parse fails or the thread count is 0, print an error and exit
+            Err(_) | Ok(0) => {
+                eprintln!("[error] invalid number of threads. threads must be a positive int");
+                std::process::exit(1);
+            },
+            // If it parses, set the number of threads (ignored without the parallel feature)
+            Ok(threads) => if !bands::setthreads(threads) {
+                eprintln!("[info] ignoring the number of threads. automata was built without the 'parallel' feature.");
+            }
+        }
+    }
 
-    // Check if an automaton has been specified and create the simulator grid for it
-    match matches.value_of("AUTOMATON") {
-        // No automaton specified
+    // Check for the seed argument
+    let seed = match matches.value_of("SEED") {
+        // If the seed is not set, generate one and print it (resumed runs keep their seed)
         None => {
+            let seed = automata::commons::random::entropy_seed();
+            if !matches.is_present("RESUME") {
+                eprintln!("[info] no seed set. using seed {}", seed);
+            }
+            seed
+        },
+        // If the seed is set
+        Some(seed) => {
+            // Parse the seed into an integer
+            match seed.parse::<u64>() {
+                // If the parse fails, print an error and exit
+                Err(_) => {
+                    eprintln!("[error] invalid seed. seed must be an unsigned int");
+                    std::process::exit(1);
+                },
+                // If it parses, set the seed
+                Ok(s) => s
+            }
+        }
+    };
+
+    // Check for the history size argument
+    let history = match matches.value_of("HISTORY").unwrap_or("100").parse::<usize>() {
+        // If the parse fails, print an error and exit
+        Err(_) => {
+            eprintln!("[error] invalid history size. history must be an unsigned int");
+            std::process::exit(1);
+        },
+        // If it parses, set the history size
+        Ok(h) => h
+    };
+
+    // Check for the boundary argument (validated by clap)
+    let boundary = match matches.value_of("BOUNDARY").map(str::parse::<Boundary>) {
+        // If the boundary is not set
+        None => None,
+        // If the boundary parses, set it
+        Some(Ok(b)) => Some(b),
+        // If the parse fails, print an error and exit
+        Some(Err(err)) => {
+            eprintln!("[error] {}", err);
+            std::process::exit(1);
+        }
+    };
+
+    // Check for the snapshot and resume arguments
+    let snapshot = matches.value_of("SNAPSHOT").map(PathBuf::from);
+    let resume = matches.value_of("RESUME").map(Path::new);
+
+    // Check for the halt argument
+    let untilhalt = matches.is_present("UNTILHALT");
+    // Check for the output format argument (validated by clap)
+    let format = matches.value_of("FORMAT").unwrap_or("csv");
+    // Run headless if a stop condition has been given
+    let headless = generations.is_some() || untilhalt;
+
+    // Look up the specified automaton and backend in the registry (validated by clap)
+    let automaton = matches.value_of("AUTOMATON").unwrap_or_default();
+    let backend = matches.value_of("BACKEND").unwrap_or("grid");
+    let entry = match registry.get(automaton, backend) {
+        Some(entry) => entry,
+        // Unsupported Automaton - Print an error and exit
+        None if registry.backends_of(automaton).is_empty() => {
+            eprintln!("[error] invalid automaton specified.");
+            std::process::exit(1);
+        },
+        // Unsupported Backend - Print an error with the valid backends and exit
+        None => {
+            eprintln!("[error] '{}' does not support the '{}' backend. valid backends are: {}", automaton, backend, registry.backends_of(automaton).join(", "));
+            std::process::exit(1);
+        },
+    };
+
+    // Check for the pattern argument
+    let pattern = match matches.value_of("PATTERN").map(|path| Pattern::load(Path::new(path))) {
+        // If the pattern is not set
+        None => None,
+        // If the pattern loads, set it
+        Some(Ok(p)) => Some(p),
+        // If the pattern could not be loaded, print an error and exit
+        Some(Err(err)) => {
+            eprintln!("[error] could not load pattern. {}", err);
+            std::process::exit(1);
+        }
+    };
+
+    // Check for the pattern position argument (the pattern is centred if not set)
+    let position = matches.value_of("AT").map(|at| parse_position(at, "pattern position"));
+    let placement = match position {
+        None => Placement::Centre,
+        Some((x, y)) => Placement::At(x, y),
+    };
+
+    // Check that the automaton supports the initial state (patterns are placed on an empty grid by default)
+    let init = match (matches.value_of("INIT"), &pattern, position) {
+        // A built-in pattern placed at a position
+        (Some(init), None, Some((x, y))) => format!("{}@{}x{}", init, x, y),
+        (Some(init), _, _) => init.to_string(),
+        (None, Some(_), _) if entry.info.supports("empty") => "empty".to_string(),
+        (None, None, Some(_)) => {
             // Print an error and exit
-            eprintln!("[error] no automaton specified.");
-            std::process::exit(0);
+            eprintln!("[error] a position requires a pattern or a built-in pattern initial state.");
+            std::process::exit(1);
         },
-        // Some automaton specified. Check the value
-        Some(name) => match name {
-            // Conway's Game of Life
-            "gameoflife" => {
-                let sim = &mut Simulation::<automata::gameoflife::GameOfLife<CellGrid<BinaryCell>>>::new("default", cell_size, fps);
-                rendersim(sim, grid_w, grid_h, cell_size, fps, &author)
-            }, 
-            // Langton's Ant
-            "langtonsant" => {
-                let sim = &mut Simulation::<automata::langtonsant::LangtonsAnt<CellGrid<BinaryCell>>>::new("default", cell_size, fps);
-                rendersim(sim, grid_w, grid_h, cell_size, fps, &author)
-            },  
-
-            // Unsupported Automaton - Print an error and exit
-            _ => {
-                eprintln!("[error] invalid automaton specified.");
-                std::process::exit(0);
-            },
+        (None, _, _) => "default".to_string(),
+    };
+    let init = init.as_str();
+    if !entry.info.supports(init) {
+        // Print an error with the valid initial states and exit
+        eprintln!("[error] invalid initial state '{}' for '{}'. valid states are: {}", init, entry.info.cliname, entry.info.initialstates.join(", "));
+        std::process::exit(1);
+    }
+
+    // Create the simulation of the automaton
+    let sim = &mut *createsim(entry, init, resume, seed, cell_size, fps);
+    sim.setsnapshotpath(snapshot);
+    sim.setstride(stride);
+    sim.setexportpath(matches.value_of("EXPORT").map(PathBuf::from));
+    // Set the boundary policy (resumed simulations keep their own unless it is set)
+    if let Some(boundary) = boundary {
+        if let Err(err) = sim.setboundary(boundary) {
+            // Print an error and exit
+            eprintln!("[error] {}", err);
+            std::process::exit(1);
+        }
+    }
+    // Set the rule (resumed simulations keep their own unless it is set)
+    if let Some(rule) = matches.value_of("RULE") {
+        if let Err(err) = sim.setrule(rule) {
+            // Print an error and exit
+            eprintln!("[error] {}", err);
+            std::process::exit(1);
+        }
+    }
+    // Set the pattern to place once the grid is initialized
+    if let Some(pattern) = pattern {
+        // Use the rule of the pattern unless a rule has been set
+        if let (None, Some(rule)) = (matches.value_of("RULE"), &pattern.rule) {
+            if let Err(err) = sim.setrule(rule) {
+                eprintln!("[info] ignoring the rule of the pattern. {}", err);
+            }
+        }
+        sim.setpattern(pattern, placement);
+    }
+
+    // Check whether exports are cropped to the live cells
+    let bounded = !matches.is_present("EXPORTFULL");
+    match headless {
+        true => batchsim(sim, grid_cols, grid_rows, generations, untilhalt, format, bounded),
+        false => {
+            sim.sethistory(history);
+            // The grid follows the window if the window size is derived from the grid size in pixels.
+            // A grid size set in cells is kept when the window is resized.
+            let fitted = matches.value_of("WINDOW").is_none() && matches.value_of("CELLS").is_none();
+            rendersim(sim, grid_cols, grid_rows, fitted, window_w, window_h, author)
         },
     }
 }
 
-// A function that renders the simulation in a window
-fn rendersim<T: Automaton>(automaton: &mut Result<Simulation<T>, ggez::GameError>, grid_w: f32, grid_h: f32, cell_size: f32, fps: u32, author: &str) -> ggez::GameResult {
-    use ggez::{conf, event};
-
-    // Check if the automaton has any errors
-    match automaton {
-        // Render the automaton
-        Ok(simulation) => {
-            // Get the name of the automaton
-            let simname = simulation.automaton.name();
-            // Create ggez WindowMode.
-            let w_mode: conf::WindowMode = conf::WindowMode::default().dimensions(grid_w, grid_h + 60.0);
-            // Create ggez Window with the automaton name
-            let w_setup = conf::WindowSetup::default().title(simname.as_str());
-            // Create a ggez context with the window mode and window setup
-            let cb = ggez::ContextBuilder::new(simname.as_str(), author)
-                .window_mode(w_mode)
-                .window_setup(w_setup);
-
-            // Print the simulation config
-            println!("Running {} | {}x{} | {}px @ {} FPS", simname, grid_w, grid_h, cell_size, fps);
-            // Build the context and event loop
-            let (ctx, event_loop) = &mut cb.build()?;
-            // Start the simulation event loop
-            event::run(ctx, event_loop, simulation)
+// A function that parses a position in the XxY format into integers.
+// Prints an error and exits if the position is invalid.
+fn parse_position(value: &str, name: &str) -> (isize, isize) {
+    // Split position string, only 2 values expected (x and y)
+    let position = value.split('x').map(str::parse::<isize>).collect::<Vec<_>>();
+    match position.as_slice() {
+        [Ok(x), Ok(y)] => (*x, *y),
+        // Print an error and exit
+        _ => {
+            eprintln!("[error] invalid {}. must be in XxY format with integers.", name);
+            std::process::exit(1);
+        }
+    }
+}
+
+// A function that parses dimensions in the WIDTHxHEIGHT format into floats.
+// Prints an error and exits if the dimensions are invalid.
+fn parse_dimensions(value: &str, name: &str) -> (f32, f32) {
+    // Split dimensions string
+    let dimensions = value.split('x').collect::<Vec<&str>>();
+    // Check number of dimension values, only 2 values expected (width and height)
+    if dimensions.len() != 2 {
+        // Print an error and exit
+        eprintln!("[error] invalid {} dimensions. must be in WIDTHxHEIGHT format.", name);
+        std::process::exit(1);
+    }
+
+    // Parse the width into a float
+    let width = match dimensions[0].parse::<f32>() {
+        // If the parse fails, print an error and exit
+        Err(_) => {
+            eprintln!("[error] invalid {} dimensions. width must be a float", name);
+            std::process::exit(1);
+        },
+        // If it parses, set the width
+        Ok(w) => w
+    };
+
+    // Parse the height into a float
+    let height = match dimensions[1].parse::<f32>() {
+        // If the parse fails, print an error and exit
+        Err(_) => {
+            eprintln!("[error] invalid {} dimensions. height must be a float", name);
+            std::process::exit(1);
         },
+        // If it parses, set the height
+        Ok(h) => h
+    };
 
+    (width, height)
+}
+
+// A function that parses a grid size in cells in the WIDTHxHEIGHT format into integers.
+// Prints an error and exits if the grid size is invalid or has no cells.
+fn parse_cells(value: &str) -> (usize, usize) {
+    // Split dimensions string, only 2 values expected (width and height)
+    let dimensions = value.split('x').collect::<Vec<&str>>();
+    if dimensions.len() != 2 {
         // Print an error and exit
-        Err(err) => {
-            eprintln!("[error] could not render simulation. {}", err);
-            std::process::exit(0);
+        eprintln!("[error] invalid cells dimensions. must be in WIDTHxHEIGHT format.");
+        std::process::exit(1);
+    }
+
+    // Parse the width and height into positive integers
+    match (dimensions[0].parse::<usize>(), dimensions[1].parse::<usize>()) {
+        (Ok(width), Ok(height)) if width > 0 && height > 0 => (width, height),
+        // If either parse fails or is 0, print an error and exit
+        _ => {
+            eprintln!("[error] invalid cells dimensions. width and height must be positive ints");
+            std::process::exit(1);
+        }
+    }
+}
+
+// A function that creates a new simulation of the automaton in the given registry entry
+// with the given initial state or resumes one from a snapshot if a path is given.
+// Prints an error and exits if the simulation could not be created or resumed.
+fn createsim(entry: &Entry, init: &str, resume: Option<&Path>, seed: u64, cell_size: f32, fps: u32) -> Box<dyn Runner> {
+    match resume {
+        // Create a new simulation
+        None => match (entry.create)(init, seed, cell_size, fps) {
+            Ok(simulation) => simulation,
+            Err(err) => {
+                eprintln!("[error] could not create simulation. {}", err);
+                std::process::exit(1);
+            }
+        },
+        // Resume the simulation from the snapshot
+        Some(path) => match (entry.resume)(path, cell_size, fps) {
+            Ok(simulation) => simulation,
+            Err(err) => {
+                eprintln!("[error] could not resume from snapshot. {}", err);
+                std::process::exit(1);
+            }
+        },
+    }
+}
+
+// A function that runs the simulation headless and prints the stats of each generation
+// to stdout. Runs for the given number of generations or until the automaton halts.
+fn batchsim(simulation: &mut dyn Runner, width: usize, height: usize, generations: Option<u32>, untilhalt: bool, format: &str, bounded: bool) {
+    // Initialize the automaton with the grid dimensions (unless resumed from a snapshot)
+    if !simulation.initialized() {
+        if let Err(err) = simulation.initialize(width, height) {
+            // Print an error and exit
+            eprintln!("[error] could not initialize simulation. {}", err);
+            std::process::exit(1);
+        }
+    }
+
+    // Lock stdout for the duration of the run
+    let stdout = std::io::stdout();
+    let mut out = stdout.lock();
+
+    // Print the CSV header
+    if format == "csv" && writeln!(out, "generation,alive,dead").is_err() {
+        return;
+    }
+
+    loop {
+        // Format the stats of the current generation
+        let line = match format {
+            "json" => format!("{{\"generation\":{},\"alive\":{},\"dead\":{}}}", simulation.generation(), simulation.alive(), simulation.dead()),
+            _ => format!("{},{},{}", simulation.generation(), simulation.alive(), simulation.dead()),
+        };
+
+        // Print the stats (stop if stdout has been closed)
+        if writeln!(out, "{}", line).is_err() {
+            return;
+        }
+
+        // Check if the requested number of generations has been reached
+        if let Some(generations) = generations {
+            if simulation.generation() >= generations {
+                break;
+            }
+
+            // Shorten the last stride so that it ends on the requested generation
+            let remaining = generations - simulation.generation();
+            if simulation.stride() > remaining {
+                simulation.setstride(remaining);
+            }
+        }
+        // Check if the automaton has halted
+        if untilhalt && simulation.halted() {
+            break;
         }
-    }        
+
+        // Advance the automaton to the next generation (stop if it cannot progress)
+        if !simulation.step() {
+            break;
+        }
+    }
+
+    // Save a snapshot of the automaton if a snapshot path is set
+    if simulation.hassnapshotpath() {
+        let path = simulation.snapshot_path();
+        if let Err(err) = simulation.save(&path) {
+            // Print an error and exit
+            eprintln!("[error] could not save snapshot. {}", err);
+            std::process::exit(1);
+        }
+    }
+
+    // Export the live cells of the grid as a pattern if an export path is set
+    if simulation.hasexportpath() {
+        let path = simulation.export_path();
+        if let Err(err) = simulation.export(&path, bounded) {
+            // Print an error and exit
+            eprintln!("[error] could not export pattern. {}", err);
+            std::process::exit(1);
+        }
+    }
+}
+
+// A function that renders the simulation in a window
+#[cfg(feature = "graphics")]
+fn rendersim(simulation: &mut dyn Runner, width: usize, height: usize, fitted: bool, window_w: f32, window_h: f32, author: &str) {
+    use ggez::conf;
+    use automata::simulation::simevents::BANNER_HEIGHT;
+
+    // Set the grid size (in cells) unless resumed from a snapshot (which keeps the size of its grid)
+    if !simulation.initialized() {
+        simulation.setgridsize(width, height, fitted);
+    }
+
+    // Get the name of the automaton
+    let simname = simulation.name();
+    // Create ggez WindowMode.
+    // The window is resizable and the grid is resized to fit it.
+    let w_mode: conf::WindowMode = conf::WindowMode::default()
+        .dimensions(window_w, window_h + BANNER_HEIGHT)
+        .resizable(true);
+    // Create ggez Window with the automaton name
+    let w_setup = conf::WindowSetup::default().title(simname.as_str());
+    // Create a ggez context with the window mode and window setup
+    let cb = ggez::ContextBuilder::new(simname.as_str(), author)
+        .window_mode(w_mode)
+        .window_setup(w_setup);
+
+    // Print the simulation config
+    println!("Running {} | {}x{} cells | {}px @ {} FPS | Seed {}", simname, width, height, simulation.cellsize(), simulation.fps(), simulation.seed());
+
+    // Build the context and event loop and start the simulation event loop
+    let result = cb.build().and_then(|(ctx, event_loop)| {
+        simulation.run(&mut { ctx }, &mut { event_loop })
+    });
+
+    // Print an error and exit if the simulation could not be rendered
+    if let Err(err) = result {
+        eprintln!("[error] could not render simulation. {}", err);
+        std::process::exit(1);
+    }
+}
+
+// A function that stands in for the renderer when built without the graphics feature
+#[cfg(not(feature = "graphics"))]
+fn rendersim(_: &mut dyn Runner, _: usize, _: usize, _: bool, _: f32, _: f32, _: &str) {
+    // Print an error and exit
+    eprintln!("[error] could not render simulation. automata was built without the 'graphics' feature.");
+    std::process::exit(1);
 }
@@ -0,0 +1,65 @@
+/// Represents the smallest zoom level of a camera.
+pub const MIN_ZOOM: f32 = 0.05;
+/// Represents the largest zoom level of a camera.
+pub const MAX_ZOOM: f32 = 20.0;
+
+/// A struct that represents a camera over the simulation grid.
+/// The camera maps grid space (pixels at a zoom of 1) onto screen space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Camera {
+    /// Represents the screen position of the grid origin.
+    pub offset: (f32, f32),
+    /// Represents the scale factor from grid space to screen space.
+    pub zoom: f32,
+}
+
+/// Implementation of the Default trait for Camera
+impl Default for Camera {
+    /// A method that returns a camera with the grid origin at
+    /// the top left corner of the screen and no zoom.
+    fn default() -> Self {
+        Self {
+            offset: (0.0, 0.0),
+            zoom: 1.0,
+        }
+    }
+}
+
+/// Implementation of methods for Camera
+impl Camera {
+    /// A method that converts a position on the screen into grid space.
+    pub fn screen_to_grid(&self, x: f32, y: f32) -> (f32, f32) {
+        ((x - self.offset.0) / self.zoom, (y - self.offset.1) / self.zoom)
+    }
+
+    /// A method that converts a position in grid space into a position on the screen.
+    pub fn grid_to_screen(&self, x: f32, y: f32) -> (f32, f32) {
+        ((x * self.zoom) + self.offset.0, (y * self.zoom) + self.offset.1)
+    }
+
+    /// A method that moves the camera by the given distance on the screen.
+    pub fn pan(&mut self, dx: f32, dy: f32) {
+        self.offset = (self.offset.0 + dx, self.offset.1 + dy);
+    }
+
+    /// A method that scales the zoom by the given factor, keeping the grid
+    /// position under the given screen position fixed. The zoom is clamped
+    /// between MIN_ZOOM and MAX_ZOOM.
+    pub fn zoom_at(&mut self, factor: f32, x: f32, y: f32) {
+        // Calculate the new zoom level
+        let zoom = (self.zoom * factor).clamp(MIN_ZOOM, MAX_ZOOM);
+        // Calculate the effective scale change after clamping
+        let scale = zoom / self.zoom;
+
+        // Move the grid origin so that the anchor stays under the screen position
+        self.offset = (x - (x - self.offset.0) * scale, y - (y - self.offset.1) * scale);
+        self.zoom = zoom;
+    }
+
+    /// A method that returns the region of grid space that is visible
+    /// on a screen of the given size as (x, y, width, height).
+    pub fn visible(&self, width: f32, height: f32) -> (f32, f32, f32, f32) {
+        let (x, y) = self.screen_to_grid(0.0, 0.0);
+        (x, y, width / self.zoom, height / self.zoom)
+    }
+}
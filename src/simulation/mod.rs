@@ -1,8 +1,24 @@
 pub mod simulables;
+pub mod camera;
+pub mod simsnapshot;
+pub mod simhistory;
+pub mod simrunner;
+pub mod simregistry;
+#[allow(clippy::module_inception)]
 pub mod simulation;
+#[cfg(feature = "graphics")]
+pub mod simrender;
+#[cfg(feature = "graphics")]
 pub mod simevents;
 
 pub use crate::simulation::simulables::SimCell;
 pub use crate::simulation::simulables::SimGrid;
 pub use crate::simulation::simulables::Automaton;
-pub use crate::simulation::simulation::Simulation;
\ No newline at end of file
+pub use crate::simulation::simulation::Simulation;
+pub use crate::simulation::camera::Camera;
+pub use crate::simulation::simsnapshot::Snapshot;
+pub use crate::simulation::simhistory::History;
+pub use crate::simulation::simrunner::{Runnable, Runner};
+pub use crate::simulation::simregistry::{AutomatonInfo, Registry};
+#[cfg(feature = "graphics")]
+pub use crate::simulation::simrender::Renderable;
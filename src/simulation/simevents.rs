@@ -1,28 +1,46 @@
 use ggez::timer;
 use ggez::event;
 use ggez::graphics;
-use ggez::GameResult;
+use ggez::{GameError, GameResult};
+use ggez::input::keyboard::{KeyCode, KeyMods};
+use ggez::input::mouse::{self, MouseButton};
 use ggez::nalgebra as na;
+use serde::Serialize;
 
-use crate::simulation::Automaton;
+use crate::simulation::Renderable;
 use crate::simulation::Simulation;
+use crate::simulation::SimGrid;
+use crate::simulation::Camera;
+
+/// Represents the height of the banner below the grid (pixels).
+pub const BANNER_HEIGHT: f32 = 60.0;
+/// Represents the zoom factor applied for every step of the mouse wheel.
+pub const ZOOM_STEP: f32 = 1.2;
 
 /// Implementation of the EventHandler trait for Simulation
-impl<T: Automaton> event::EventHandler for Simulation<T> {
+impl<T: Renderable + Serialize> event::EventHandler for Simulation<T> {
 
     /// A method that is called when the simulation update is triggered by the event loop
     fn update(&mut self, ctx: &mut ggez::Context) -> GameResult<()> {
+        // If the simulation is paused, don't advance the automaton
+        if self.paused {
+            // Drain the elapsed FPS time so that the automaton
+            // does not catch up on missed updates when resumed
+            if self.fps != 0 {
+                while timer::check_update_time(ctx, self.fps) {}
+            }
+
         // If FPS is set to 0, then no rate-limiting
-        if self.fps == 0 {
+        } else if self.fps == 0 {
             // Advance the automaton state
-            self.automaton.advance();
-        
+            self.advance();
+
         // Otherwise refresh the graphics with the set FPS rate
         } else {
             // Wait for the FPS time to elapse
             while timer::check_update_time(ctx, self.fps) {
                 // Advance the automaton state
-                self.automaton.advance();
+                self.advance();
             }
         }
 
@@ -35,26 +53,256 @@ impl<T: Automaton> event::EventHandler for Simulation<T> {
         // Clear the graphics window
         graphics::clear(ctx, [0.0, 0.0, 0.0, 1.0].into());
 
+        // Retrieve the size of the graphics window
+        let screen = graphics::screen_coordinates(ctx);
+
         // Check if the simulation has been initialized
         if !self.initialized {
-            // Retrieve the size of the graphics window
-            let width = graphics::screen_coordinates(ctx).w;
-            let height = graphics::screen_coordinates(ctx).h;
-            // Create rectangle dimensions
-            let dimensions = graphics::Rect::new(0.0, 0.0, width, height);
+            // Use the grid size if set, otherwise calculate the
+            // number of cells that fit in the window (banner removed)
+            let (width, height) = self.gridsize.unwrap_or((
+                (screen.w / self.cellsize) as usize,
+                ((screen.h - BANNER_HEIGHT) / self.cellsize) as usize,
+            ));
 
-            // Initialize the automaton with the screen dimensions
-            self.automaton.initialize(dimensions);
-            // Set the initialized flag to true
-            self.initialized = true;
+            // Initialize the automaton with the grid dimensions (stop the event loop if it fails)
+            if let Err(err) = self.initialize(width, height) {
+                return Err(GameError::ResourceLoadError(err.to_string()));
+            }
         }
 
-        // Render the automaton state
-        self.automaton.draw(ctx, (na::Point2::new(0.0, 0.0),).into())?;
+        // Calculate the region of the grid that is visible through the camera
+        let (vx, vy, vw, vh) = self.camera.visible(screen.w, screen.h - BANNER_HEIGHT);
+        let visible = graphics::Rect::new(vx, vy, vw, vh);
+        // Calculate the bounds of the grid (in grid space)
+        let bounds = self.automaton.extent(self.cellsize);
+
+        // Check if any of the grid is visible
+        if visible.overlaps(&bounds) && bounds.w > 0.0 && bounds.h > 0.0 {
+            // Render the automaton state into a mesh
+            let mut mb = graphics::MeshBuilder::new();
+            self.automaton.render(&mut mb, self.cellsize, visible)?;
+            // Build and Draw the mesh through the camera
+            let mesh = mb.build(ctx)?;
+            let param = graphics::DrawParam::new()
+                .dest(na::Point2::new(self.camera.offset.0, self.camera.offset.1))
+                .scale(na::Vector2::new(self.camera.zoom, self.camera.zoom));
+            graphics::draw(ctx, &mesh, param)?;
+        }
+
+        // Render the banner below the grid
+        self.draw_banner(ctx)?;
         // Present the graphics on the window
         graphics::present(ctx)?;
-        
+
         // Return an GameResult::Ok
         Ok(())
     }
-}
\ No newline at end of file
+
+    /// A method that is called when the window is resized. The screen coordinates are set
+    /// to the new size of the window. If the size of the grid is derived from the window and
+    /// the camera is not zoomed, the grid is resized to the cells that fit in the window
+    /// (banner removed). The cells of the grid stay anchored to its top left corner.
+    fn resize_event(&mut self, ctx: &mut ggez::Context, width: f32, height: f32) {
+        // Ignore events that do not change the size of the window (ex: when it is created)
+        let screen = graphics::screen_coordinates(ctx);
+        if (screen.w, screen.h) == (width, height) {
+            return;
+        }
+
+        // Map the screen coordinates onto the resized window (the window is stretched otherwise)
+        if let Err(err) = graphics::set_screen_coordinates(ctx, graphics::Rect::new(0.0, 0.0, width, height)) {
+            eprintln!("[error] could not resize window. {}", err);
+            return;
+        }
+
+        // Keep the grid if its size is not derived from the window or the camera is zoomed
+        // (the cells that fit in the window do not match the visible cells while zoomed)
+        if !self.fitted || self.camera.zoom != 1.0 {
+            return;
+        }
+
+        // Calculate the number of cells that fit in the window (banner removed)
+        let (cols, rows) = (
+            (width / self.cellsize) as usize,
+            ((height - BANNER_HEIGHT).max(0.0) / self.cellsize) as usize,
+        );
+        // Keep the grid if the window is too small to hold any cells
+        if cols == 0 || rows == 0 {
+            return;
+        }
+
+        // Resize the grid to fit the window
+        if let Err(err) = self.resize(cols, rows) {
+            eprintln!("[error] could not resize grid. {}", err);
+        }
+    }
+
+    /// A method that is called when a key is pressed. Handles the simulation controls:
+    /// - ``Space`` pauses and resumes the simulation
+    /// - ``Right`` or ``S`` advances a paused simulation by one generation
+    /// - ``Left`` or ``B`` pauses the simulation and rewinds it by one generation
+    /// - ``Up`` or ``+`` speeds up the simulation
+    /// - ``Down`` or ``-`` slows down the simulation
+    /// - ``Home`` resets the camera
+    /// - ``F5`` saves a snapshot of the automaton
+    /// - ``F6`` exports the live cells of the grid as a pattern
+    /// - ``Escape`` quits the simulation
+    fn key_down_event(&mut self, ctx: &mut ggez::Context, keycode: KeyCode, _keymods: KeyMods, _repeat: bool) {
+        match keycode {
+            // Pause or resume the simulation
+            KeyCode::Space => self.toggle_pause(),
+            // Step the simulation by a single generation if it is paused
+            KeyCode::Right | KeyCode::S if self.paused => { self.step(); },
+            // Pause the simulation and rewind it by a single generation
+            KeyCode::Left | KeyCode::B => {
+                self.paused = true;
+                self.rewind();
+            },
+            // Speed up the simulation
+            KeyCode::Up | KeyCode::Add | KeyCode::Equals => self.speedup(),
+            // Slow down the simulation
+            KeyCode::Down | KeyCode::Subtract | KeyCode::Minus => self.slowdown(),
+            // Reset the camera
+            KeyCode::Home => self.camera = Camera::default(),
+            // Save a snapshot of the automaton
+            KeyCode::F5 => {
+                let path = self.snapshot_path();
+                match self.save(&path) {
+                    Ok(_) => println!("Saved snapshot to {}", path.display()),
+                    Err(err) => eprintln!("[error] could not save snapshot. {}", err),
+                }
+            },
+            // Export the live cells of the grid as a pattern
+            KeyCode::F6 => {
+                let path = self.export_path();
+                match self.export(&path, true) {
+                    Ok(_) => println!("Exported pattern to {}", path.display()),
+                    Err(err) => eprintln!("[error] could not export pattern. {}", err),
+                }
+            },
+            // Quit the simulation
+            KeyCode::Escape => event::quit(ctx),
+            // Ignore any other key
+            _ => {},
+        }
+    }
+
+    /// A method that is called when a mouse button is pressed.
+    /// A left click toggles the cell under the cursor and starts painting.
+    fn mouse_button_down_event(&mut self, ctx: &mut ggez::Context, button: MouseButton, x: f32, y: f32) {
+        if button == MouseButton::Left {
+            if let Some((cx, cy)) = self.screen_to_cell(ctx, x, y) {
+                self.start_brush(cx, cy);
+            }
+        }
+    }
+
+    /// A method that is called when a mouse button is released. Stops painting.
+    fn mouse_button_up_event(&mut self, _ctx: &mut ggez::Context, button: MouseButton, _x: f32, _y: f32) {
+        if button == MouseButton::Left {
+            self.stop_brush();
+        }
+    }
+
+    /// A method that is called when the mouse is moved. Pans the camera while the
+    /// right button is held and paints the cell under the cursor while the left button is held.
+    fn mouse_motion_event(&mut self, ctx: &mut ggez::Context, x: f32, y: f32, dx: f32, dy: f32) {
+        // Pan the camera by the distance moved
+        if mouse::button_pressed(ctx, MouseButton::Right) {
+            self.camera.pan(dx, dy);
+        }
+
+        // Paint the cell under the cursor
+        if let Some((cx, cy)) = self.screen_to_cell(ctx, x, y) {
+            self.paint(cx, cy);
+        }
+    }
+
+    /// A method that is called when the mouse wheel is scrolled.
+    /// Zooms the camera in or out around the cursor.
+    fn mouse_wheel_event(&mut self, ctx: &mut ggez::Context, _x: f32, y: f32) {
+        // Get the position of the cursor
+        let cursor = mouse::position(ctx);
+
+        // Zoom in when scrolling away from the user and out when scrolling towards them
+        if y > 0.0 {
+            self.camera.zoom_at(ZOOM_STEP, cursor.x, cursor.y);
+        } else if y < 0.0 {
+            self.camera.zoom_at(1.0 / ZOOM_STEP, cursor.x, cursor.y);
+        }
+    }
+}
+
+// Implementation of helper methods for the Simulation event loop
+impl<T: Renderable> Simulation<T> {
+    // A method that converts a position on the screen into the position of the grid cell
+    // under it. Returns None if the position is not on the grid or is on the banner.
+    fn screen_to_cell(&self, ctx: &ggez::Context, x: f32, y: f32) -> Option<(isize, isize)> {
+        // Check that the position is not on the banner
+        if y >= graphics::screen_coordinates(ctx).h - BANNER_HEIGHT {
+            return None;
+        }
+
+        // Convert the position into grid space through the camera
+        let (gx, gy) = self.camera.screen_to_grid(x, y);
+        // Check that the position is not left or above the grid
+        if gx < 0.0 || gy < 0.0 {
+            return None;
+        }
+
+        // Calculate the cell position from the cell size
+        let (cx, cy) = ((gx / self.cellsize) as usize, (gy / self.cellsize) as usize);
+        // Check that the cell position is within the grid
+        let grid = self.automaton.grid();
+        if cx < grid.getwidth() && cy < grid.getheight() {
+            Some((cx as isize, cy as isize))
+        } else {
+            None
+        }
+    }
+
+    // A method that draws the automaton name and state in the banner below the grid
+    fn draw_banner(&self, ctx: &mut ggez::Context) -> GameResult {
+        // Declare a variable for the font size
+        let font_size = 18.0;
+
+        // Create the text graphics for the banner
+        let mut name_text = graphics::Text::new(self.automaton.fullname());
+        let mut state_text = graphics::Text::new(format!("{} | {}", self.automaton.state(), self.speed()));
+
+        // Set the font styling for the text graphics
+        state_text.set_font(graphics::Font::default(), graphics::Scale::uniform(font_size));
+        name_text.set_font(graphics::Font::default(), graphics::Scale::uniform(font_size));
+
+        // Calculate the top of the banner from the window height
+        let screen = graphics::screen_coordinates(ctx);
+        let top = screen.h - BANNER_HEIGHT;
+
+        // Draw the banner background over any of the grid that extends below it
+        let background = graphics::Mesh::new_rectangle(
+            ctx,
+            graphics::DrawMode::fill(),
+            graphics::Rect::new(0.0, top, screen.w, BANNER_HEIGHT),
+            [0.0, 0.0, 0.0, 1.0].into(),
+        )?;
+        graphics::draw(ctx, &background, graphics::DrawParam::default())?;
+
+        // Calculate the spacing between banner elements.
+        // Assumes 2 units of spacing above the name text and below the state text
+        // and 1 unit of spacing between the name and state text.
+        let spacing = (BANNER_HEIGHT - (font_size * 2.0)) / 5.0;
+
+        // Calculate the position of the name text
+        let name_offset = top + (spacing * 2.0);
+        // Calculate the position of the state text
+        let state_offset = top + (spacing * 3.0) + font_size;
+
+        // Draw the banner text graphics
+        graphics::draw(ctx, &name_text, (na::Point2::new(10.0, name_offset),))?;
+        graphics::draw(ctx, &state_text, (na::Point2::new(10.0, state_offset),))?;
+
+        // Return GameResult::Ok
+        Ok(())
+    }
+}
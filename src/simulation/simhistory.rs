@@ -0,0 +1,168 @@
+use std::collections::VecDeque;
+
+use crate::commons::grids::GridPoint;
+use crate::simulation::{Automaton, SimGrid};
+
+/// A type alias for the cells of the grid of an automaton
+type CellOf<T> = <<T as Automaton>::Grid as SimGrid>::Cell;
+
+/// A struct that represents a single entry of the history of an automaton.
+/// Stores the difference from the previous state rather than a full copy.
+pub struct Frame<T> where T: Automaton {
+    /// Represents the previous state of every cell that changed.
+    pub changes: Vec<GridPoint<CellOf<T>>>,
+    /// Represents the previous checkpoint of the automaton.
+    pub checkpoint: T::Checkpoint,
+}
+
+/// A struct that represents a bounded history of the previous states
+/// of an automaton. The oldest frames are dropped once it is full.
+pub struct History<T> where T: Automaton {
+    /// Represents the frames of the history (oldest first).
+    frames: VecDeque<Frame<T>>,
+    /// Represents the maximum number of frames in the history.
+    capacity: usize,
+}
+
+/// Implementation of methods for History
+impl<T: Automaton> History<T> {
+    /// A constructor function that creates an empty history that holds up to
+    /// the given number of frames. A capacity of 0 disables the history.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            frames: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// A getter method that returns the maximum number of frames in the history.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// A getter method that returns the number of frames in the history.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// A method that returns whether the history has no frames.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// A method that removes all the frames from the history.
+    pub fn clear(&mut self) {
+        self.frames.clear();
+    }
+
+    /// A method that records the change of the automaton from the given previous
+    /// grid and checkpoint to its current state. Nothing is recorded if neither
+    /// the grid nor the generation changed or if the history is disabled.
+    pub fn record(&mut self, grid: &T::Grid, checkpoint: T::Checkpoint, automaton: &T, generation: u32) {
+        // Check if the history is disabled
+        if self.capacity == 0 {
+            return;
+        }
+
+        // Collect the previous state of every cell that changed
+        let changes = automaton.grid().changes(grid);
+
+        // Check if the automaton has changed at all
+        if changes.is_empty() && automaton.generation() == generation {
+            return;
+        }
+
+        // Drop the oldest frame if the history is full
+        if self.frames.len() == self.capacity {
+            self.frames.pop_front();
+        }
+        // Add the frame to the history
+        self.frames.push_back(Frame { changes, checkpoint });
+    }
+
+    /// A method that rewinds the automaton to its state before the latest frame.
+    /// Returns false if the history is empty.
+    pub fn rewind(&mut self, automaton: &mut T) -> bool {
+        match self.frames.pop_back() {
+            None => false,
+            Some(frame) => {
+                // Restore the previous state of the changed cells
+                for (x, y, cell) in frame.changes {
+                    automaton.setcell(x, y, cell);
+                }
+                // Restore the previous checkpoint of the automaton
+                automaton.restore(frame.checkpoint);
+                true
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Debug;
+
+    use crate::simulation::{Automaton, History};
+    use crate::commons::cells::BinaryCell;
+    use crate::commons::grids::{BitGrid, CellGrid, Pattern, QuadTree, SparseGrid};
+    use crate::gameoflife::GameOfLife;
+
+    // Represents the number of frames that the history holds
+    const CAPACITY: usize = 5;
+
+    // A function that advances the given automaton by the given stride a number of times while
+    // recording its history, and checks that rewinding restores each of its states exactly
+    fn rewind_restores<T: Automaton>(mut automaton: T, stride: u32) where T::Checkpoint: PartialEq + Debug {
+        let mut history = History::<T>::new(CAPACITY);
+        automaton.initialize(24, 18).unwrap();
+
+        // Advance the automaton, keeping each state (the live cells and the checkpoint)
+        let mut states: Vec<(Pattern, T::Checkpoint)> = Vec::new();
+        for _ in 0..CAPACITY + 3 {
+            let (previous, checkpoint, generation) = (automaton.grid().clone(), automaton.checkpoint(), automaton.generation());
+            states.push((automaton.pattern(false).unwrap(), checkpoint.clone()));
+            automaton.advance_by(stride);
+            history.record(&previous, checkpoint, &automaton, generation);
+        }
+        assert_eq!(history.len(), CAPACITY);
+
+        // Rewind the automaton through the recorded frames (newest first)
+        for (pattern, checkpoint) in states.iter().rev().take(CAPACITY) {
+            assert!(history.rewind(&mut automaton));
+            assert_eq!(automaton.checkpoint(), *checkpoint);
+            assert_eq!(automaton.pattern(false).unwrap(), *pattern);
+        }
+
+        // Check that the oldest frames were dropped and the automaton is left as it was
+        let (pattern, checkpoint) = &states[states.len() - CAPACITY];
+        assert!(!history.rewind(&mut automaton));
+        assert_eq!(automaton.checkpoint(), *checkpoint);
+        assert_eq!(automaton.pattern(false).unwrap(), *pattern);
+        assert_eq!(automaton.generation(), (states.len() - CAPACITY) as u32 * stride);
+    }
+
+    #[test]
+    fn rewind_game_of_life() {
+        for &stride in &[1, 7] {
+            rewind_restores(GameOfLife::<CellGrid<BinaryCell>>::new("random-balanced", 3), stride);
+            rewind_restores(GameOfLife::<BitGrid>::new("random-balanced", 3), stride);
+            rewind_restores(GameOfLife::<SparseGrid>::new("random-balanced", 3), stride);
+            rewind_restores(GameOfLife::<QuadTree>::new("random-balanced", 3), stride);
+        }
+    }
+
+    #[test]
+    fn disabled_history() {
+        // A history without capacity records nothing
+        let mut automaton = GameOfLife::<CellGrid<BinaryCell>>::new("glider", 0);
+        automaton.initialize(10, 10).unwrap();
+        let mut history = History::new(0);
+        let (previous, checkpoint) = (automaton.grid().clone(), automaton.checkpoint());
+        automaton.advance();
+        history.record(&previous, checkpoint, &automaton, 0);
+
+        assert!(history.is_empty());
+        assert!(!history.rewind(&mut automaton));
+        assert_eq!(automaton.generation(), 1);
+    }
+}
@@ -0,0 +1,261 @@
+use std::path::Path;
+
+use crate::Result;
+use crate::simulation::Simulation;
+use crate::simulation::simrunner::{Runnable, Runner};
+use crate::commons::cells::BinaryCell;
+use crate::commons::grids::{BitGrid, CellGrid, QuadTree, SparseGrid};
+
+/// A struct that represents the information of an automaton in the registry.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AutomatonInfo {
+    /// Represents the name used to select the automaton on the command line.
+    pub cliname: &'static str,
+    /// Represents the name of the grid backend used to select the automaton on the command line.
+    pub backend: &'static str,
+    /// Represents the display name of the automaton.
+    pub name: &'static str,
+    /// Represents a short description of the automaton.
+    pub description: &'static str,
+    /// Represents the initial states supported by the automaton.
+    pub initialstates: &'static [&'static str],
+}
+
+/// A type alias for a function that creates a simulation from the
+/// initial state, seed, cell size (pixels) and refresh rate.
+pub type CreateFn = fn(&str, u64, f32, u32) -> Result<Box<dyn Runner>>;
+
+/// A type alias for a function that resumes a simulation from the
+/// snapshot at a path with the cell size (pixels) and refresh rate.
+pub type ResumeFn = fn(&Path, f32, u32) -> Result<Box<dyn Runner>>;
+
+/// Implementation of methods for AutomatonInfo
+impl AutomatonInfo {
+    /// A method that returns whether the automaton supports the given initial state.
+    /// A position suffix ("{state}@{x}x{y}") is ignored.
+    pub fn supports(&self, initialstate: &str) -> bool {
+        let name = initialstate.split('@').next().unwrap_or(initialstate);
+        self.initialstates.contains(&name)
+    }
+}
+
+/// A struct that represents an automaton in the registry along with
+/// the functions that create and resume simulations of it.
+#[derive(Clone, Copy)]
+pub struct Entry {
+    /// Represents the information of the automaton.
+    pub info: AutomatonInfo,
+    /// Represents a function that creates a simulation from the
+    /// initial state, seed, cell size (pixels) and refresh rate.
+    pub create: CreateFn,
+    /// Represents a function that resumes a simulation from the snapshot
+    /// at a path with the cell size (pixels) and refresh rate.
+    pub resume: ResumeFn,
+}
+
+/// A struct that represents a registry of the automata that can be simulated.
+/// Automata are looked up by their CLI name and the name of their grid backend.
+#[derive(Clone, Default)]
+pub struct Registry {
+    /// Represents the entries of the registry (in order of registration).
+    entries: Vec<Entry>,
+}
+
+/// Implementation of methods for Registry
+impl Registry {
+    /// A constructor function that creates an empty registry.
+    pub fn new() -> Self {
+        Self { entries: Vec::new() }
+    }
+
+    /// A constructor function that creates a registry with
+    /// all the automata that are built into the library.
+    pub fn builtin() -> Self {
+        let mut registry = Self::new();
+        registry
+            .register::<crate::gameoflife::GameOfLife<CellGrid<BinaryCell>>>(AutomatonInfo {
+                cliname: "gameoflife",
+                backend: "grid",
+                name: "Conway's Game of Life",
+                description: "Cells live or die based on the number of their alive neighbours (B3/S23 or the given --rule)",
+                initialstates: crate::gameoflife::INITIAL_STATES,
+            })
+            .register::<crate::gameoflife::GameOfLife<BitGrid>>(AutomatonInfo {
+                cliname: "gameoflife",
+                backend: "packed",
+                name: "Conway's Game of Life",
+                description: "Cells live or die based on the number of their alive neighbours (B3/S23 or the given --rule)",
+                initialstates: crate::gameoflife::INITIAL_STATES,
+            })
+            .register::<crate::gameoflife::GameOfLife<SparseGrid>>(AutomatonInfo {
+                cliname: "gameoflife",
+                backend: "sparse",
+                name: "Conway's Game of Life",
+                description: "Cells live or die based on the number of their alive neighbours (B3/S23 or the given --rule)",
+                initialstates: crate::gameoflife::INITIAL_STATES,
+            })
+            .register::<crate::gameoflife::GameOfLife<QuadTree>>(AutomatonInfo {
+                cliname: "gameoflife",
+                backend: "hashlife",
+                name: "Conway's Game of Life",
+                description: "Cells live or die based on the number of their alive neighbours (B3/S23 or the given --rule)",
+                initialstates: crate::gameoflife::INITIAL_STATES,
+            })
+            .register::<crate::langtonsant::LangtonsAnt<CellGrid<BinaryCell>>>(AutomatonInfo {
+                cliname: "langtonsant",
+                backend: "grid",
+                name: "Langton's Ant",
+                description: "An ant that turns and flips the cells it walks over",
+                initialstates: &["default", "empty"],
+            })
+            .register::<crate::langtonsant::LangtonsAnt<SparseGrid>>(AutomatonInfo {
+                cliname: "langtonsant",
+                backend: "sparse",
+                name: "Langton's Ant",
+                description: "An ant that turns and flips the cells it walks over",
+                initialstates: &["default", "empty"],
+            });
+
+        registry
+    }
+
+    /// A method that registers an automaton with the given information. Replaces
+    /// any automaton already registered with the same CLI name and backend.
+    pub fn register<T: Runnable>(&mut self, info: AutomatonInfo) -> &mut Self {
+        let entry = Entry {
+            info,
+            create: create::<T>,
+            resume: resume::<T>,
+        };
+
+        // Replace an existing entry with the same name or add the new entry
+        match self.entries.iter_mut().find(|e| e.info.cliname == info.cliname && e.info.backend == info.backend) {
+            Some(existing) => *existing = entry,
+            None => self.entries.push(entry),
+        }
+
+        self
+    }
+
+    /// A getter method that returns the entry for the automaton with the given CLI name and backend.
+    pub fn get(&self, cliname: &str, backend: &str) -> Option<&Entry> {
+        self.entries.iter().find(|e| e.info.cliname == cliname && e.info.backend == backend)
+    }
+
+    /// A getter method that returns all the entries of the registry.
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// A method that returns the CLI names of all the automata in the registry (without duplicates).
+    pub fn clinames(&self) -> Vec<&'static str> {
+        let mut clinames = Vec::new();
+        for entry in &self.entries {
+            if !clinames.contains(&entry.info.cliname) {
+                clinames.push(entry.info.cliname);
+            }
+        }
+
+        clinames
+    }
+
+    /// A method that returns the names of all the backends in the registry (without duplicates).
+    pub fn backends(&self) -> Vec<&'static str> {
+        let mut backends = Vec::new();
+        for entry in &self.entries {
+            if !backends.contains(&entry.info.backend) {
+                backends.push(entry.info.backend);
+            }
+        }
+
+        backends
+    }
+
+    /// A method that returns the names of the backends of the automaton with the given CLI name.
+    pub fn backends_of(&self, cliname: &str) -> Vec<&'static str> {
+        self.entries.iter()
+            .filter(|e| e.info.cliname == cliname)
+            .map(|e| e.info.backend)
+            .collect()
+    }
+}
+
+// A function that creates a new simulation of the automaton as a runner.
+fn create<T: Runnable>(initialstate: &str, seed: u64, cellsize: f32, fps: u32) -> Result<Box<dyn Runner>> {
+    Ok(Box::new(Simulation::<T>::new(initialstate, seed, cellsize, fps)?))
+}
+
+// A function that resumes a simulation of the automaton from a snapshot as a runner.
+fn resume<T: Runnable>(path: &Path, cellsize: f32, fps: u32) -> Result<Box<dyn Runner>> {
+    Ok(Box::new(Simulation::<T>::resume(path, cellsize, fps)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::simulation::{AutomatonInfo, Registry};
+    use crate::commons::grids::{BitGrid, Boundary};
+    use crate::gameoflife::GameOfLife;
+
+    #[test]
+    fn builtin_entries() {
+        let registry = Registry::builtin();
+
+        // Check the names and backends of the built-in automata
+        assert_eq!(registry.clinames(), vec!["gameoflife", "langtonsant"]);
+        assert_eq!(registry.backends(), vec!["grid", "packed", "sparse", "hashlife"]);
+        assert_eq!(registry.backends_of("gameoflife"), vec!["grid", "packed", "sparse", "hashlife"]);
+        assert_eq!(registry.backends_of("langtonsant"), vec!["grid", "sparse"]);
+        assert!(registry.backends_of("unknown").is_empty());
+        assert!(registry.get("langtonsant", "packed").is_none());
+
+        for entry in registry.entries() {
+            let info = entry.info;
+            assert_eq!(registry.get(info.cliname, info.backend).map(|e| e.info), Some(info));
+
+            // Check that every initial state of the automaton creates a simulation of it
+            for state in info.initialstates {
+                let mut runner = (entry.create)(state, 1, 10.0, 0).unwrap();
+                runner.initialize(20, 20).unwrap();
+                assert_eq!(runner.name(), info.name, "{} {} {}", info.cliname, info.backend, state);
+            }
+
+            // Check that only the bounded grids support boundaries
+            let mut runner = (entry.create)("default", 1, 10.0, 0).unwrap();
+            let bounded = matches!(info.backend, "grid" | "packed");
+            assert_eq!(runner.setboundary(Boundary::Toroidal).is_ok(), bounded, "{} {}", info.cliname, info.backend);
+
+            // Check that unsupported initial states are rejected
+            let mut runner = (entry.create)("unknown", 1, 10.0, 0).unwrap();
+            assert!(runner.initialize(20, 20).is_err());
+        }
+    }
+
+    #[test]
+    fn register_replaces() {
+        let mut registry = Registry::builtin();
+        let count = registry.entries().len();
+        let info = registry.get("gameoflife", "packed").unwrap().info;
+
+        // Registering the same name and backend replaces the entry in place
+        let renamed = AutomatonInfo { name: "Packed Life", ..info };
+        registry.register::<GameOfLife<BitGrid>>(renamed);
+        assert_eq!(registry.entries().len(), count);
+        assert_eq!(registry.entries()[1].info, renamed);
+
+        // Registering a new backend adds an entry
+        let added = AutomatonInfo { backend: "bits", ..info };
+        registry.register::<GameOfLife<BitGrid>>(added);
+        assert_eq!(registry.entries().len(), count + 1);
+        assert_eq!(registry.backends_of("gameoflife"), vec!["grid", "packed", "sparse", "hashlife", "bits"]);
+    }
+
+    #[test]
+    fn supports_states() {
+        let info = Registry::builtin().get("gameoflife", "grid").unwrap().info;
+        assert!(info.supports("glider"));
+        assert!(info.supports("glider@3x4"));
+        assert!(info.supports("default"));
+        assert!(!info.supports("unknown"));
+        assert!(!info.supports("@glider"));
+    }
+}
@@ -0,0 +1,21 @@
+use ggez::graphics;
+use ggez::GameResult;
+
+use crate::simulation::{Automaton, SimGrid};
+
+/// A trait for automata that can be rendered with ggez.
+/// The automaton is drawn into a mesh in grid space where
+/// each cell is a square with sides of the given cell size.
+pub trait Renderable: Automaton {
+    /// A method that adds the automaton grid and any agents on it to the given
+    /// mesh builder. Only the cells within the visible region (in grid space)
+    /// need to be added. It is only called when some of its extent is visible.
+    fn render(&self, mb: &mut graphics::MeshBuilder, cellsize: f32, visible: graphics::Rect) -> GameResult;
+
+    /// A method that returns the region (in grid space) that the automaton can be
+    /// rendered in. Defaults to the dimensions of the grid from the origin.
+    fn extent(&self, cellsize: f32) -> graphics::Rect {
+        let grid = self.grid();
+        graphics::Rect::new(0.0, 0.0, grid.getwidth() as f32 * cellsize, grid.getheight() as f32 * cellsize)
+    }
+}
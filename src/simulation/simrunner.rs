@@ -0,0 +1,227 @@
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::Result;
+use crate::commons::grids::{Boundary, Pattern, Placement};
+use crate::simulation::{History, Simulation};
+#[cfg(feature = "graphics")]
+use crate::simulation::Renderable;
+#[cfg(not(feature = "graphics"))]
+use crate::simulation::Automaton;
+
+/// A trait for automata that can be run through a Runner.
+/// Requires the automaton to be renderable when built with graphics.
+#[cfg(feature = "graphics")]
+pub trait Runnable: Renderable + Serialize + DeserializeOwned + 'static {}
+#[cfg(feature = "graphics")]
+impl<T: Renderable + Serialize + DeserializeOwned + 'static> Runnable for T {}
+
+/// A trait for automata that can be run through a Runner.
+#[cfg(not(feature = "graphics"))]
+pub trait Runnable: Automaton + Serialize + DeserializeOwned + 'static {}
+#[cfg(not(feature = "graphics"))]
+impl<T: Automaton + Serialize + DeserializeOwned + 'static> Runnable for T {}
+
+/// A trait for a simulation of an automaton whose type has been erased.
+/// Allows simulations of different automata to be created and run
+/// from a registry without naming their types.
+pub trait Runner {
+    /// A method that returns the name of the automaton.
+    fn name(&self) -> String;
+
+    /// A method that returns the seed of the automaton.
+    fn seed(&self) -> u64;
+
+    /// A method that returns the current generation of the automaton.
+    fn generation(&self) -> u32;
+
+    /// A method that returns the number of alive cells on the grid.
+    fn alive(&self) -> u32;
+
+    /// A method that returns the number of dead cells on the grid.
+    fn dead(&self) -> u32;
+
+    /// A method that returns whether the automaton has halted.
+    fn halted(&self) -> bool;
+
+    /// A method that returns the size of an individual cell (pixels).
+    fn cellsize(&self) -> f32;
+
+    /// A method that returns the refresh rate of the simulation.
+    fn fps(&self) -> u32;
+
+    /// A method that returns whether the simulation has been initialized.
+    fn initialized(&self) -> bool;
+
+    /// A method that initializes the automaton for the given grid size (in cells).
+    fn initialize(&mut self, width: usize, height: usize) -> Result<()>;
+
+    /// A method that sets the size of the grid (in cells) used when rendered and
+    /// whether the grid is resized to fit the window when the window is resized.
+    fn setgridsize(&mut self, width: usize, height: usize, fitted: bool);
+
+    /// A method that sets the number of generations kept in the history.
+    fn sethistory(&mut self, capacity: usize);
+
+    /// A method that returns the number of generations the automaton is advanced by at a time.
+    fn stride(&self) -> u32;
+
+    /// A method that sets the number of generations the automaton is advanced by at a time.
+    fn setstride(&mut self, stride: u32);
+
+    /// A method that sets the policy for positions beyond the edges of the grid.
+    fn setboundary(&mut self, boundary: Boundary) -> Result<()>;
+
+    /// A method that sets the rule of the automaton from its string notation.
+    fn setrule(&mut self, rule: &str) -> Result<()>;
+
+    /// A method that sets the path that snapshots are saved to.
+    fn setsnapshotpath(&mut self, path: Option<PathBuf>);
+
+    /// A method that sets the pattern placed onto the grid when the simulation is initialized.
+    fn setpattern(&mut self, pattern: Pattern, placement: Placement);
+
+    /// A method that sets the path that patterns are exported to.
+    fn setexportpath(&mut self, path: Option<PathBuf>);
+
+    /// A method that returns whether a path has been set for exported patterns.
+    fn hasexportpath(&self) -> bool;
+
+    /// A method that returns the path that patterns are exported to.
+    fn export_path(&self) -> PathBuf;
+
+    /// A method that exports the live cells of the grid as a pattern file at the given path.
+    fn export(&self, path: &Path, bounded: bool) -> Result<()>;
+
+    /// A method that returns whether a path has been set for snapshots.
+    fn hassnapshotpath(&self) -> bool;
+
+    /// A method that returns the path that snapshots are saved to.
+    fn snapshot_path(&self) -> PathBuf;
+
+    /// A method that saves a snapshot of the automaton to the given path.
+    fn save(&self, path: &Path) -> Result<()>;
+
+    /// A method that advances the automaton until it reaches its next generation.
+    /// Returns false if the automaton halted before reaching a new generation.
+    fn step(&mut self) -> bool;
+
+    /// A method that runs the simulation event loop with the given context.
+    #[cfg(feature = "graphics")]
+    fn run(&mut self, ctx: &mut ggez::Context, events: &mut ggez::event::EventsLoop) -> ggez::GameResult;
+}
+
+/// Implementation of the Runner trait for Simulation
+impl<T: Runnable> Runner for Simulation<T> {
+    fn name(&self) -> String {
+        self.automaton.name()
+    }
+
+    fn seed(&self) -> u64 {
+        self.automaton.seed()
+    }
+
+    fn generation(&self) -> u32 {
+        self.automaton.generation()
+    }
+
+    fn alive(&self) -> u32 {
+        self.automaton.alive()
+    }
+
+    fn dead(&self) -> u32 {
+        self.automaton.dead()
+    }
+
+    fn halted(&self) -> bool {
+        self.automaton.halted()
+    }
+
+    fn cellsize(&self) -> f32 {
+        self.cellsize
+    }
+
+    fn fps(&self) -> u32 {
+        self.fps
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn initialize(&mut self, width: usize, height: usize) -> Result<()> {
+        Simulation::initialize(self, width, height)
+    }
+
+    fn setgridsize(&mut self, width: usize, height: usize, fitted: bool) {
+        self.gridsize = Some((width, height));
+        self.fitted = fitted;
+    }
+
+    fn sethistory(&mut self, capacity: usize) {
+        self.history = History::new(capacity);
+    }
+
+    fn stride(&self) -> u32 {
+        self.stride
+    }
+
+    fn setstride(&mut self, stride: u32) {
+        Simulation::setstride(self, stride)
+    }
+
+    fn setboundary(&mut self, boundary: Boundary) -> Result<()> {
+        self.automaton.setboundary(boundary)
+    }
+
+    fn setrule(&mut self, rule: &str) -> Result<()> {
+        self.automaton.setrule(rule)
+    }
+
+    fn setsnapshotpath(&mut self, path: Option<PathBuf>) {
+        self.snapshotpath = path;
+    }
+
+    fn setpattern(&mut self, pattern: Pattern, placement: Placement) {
+        self.pattern = Some((pattern, placement));
+    }
+
+    fn setexportpath(&mut self, path: Option<PathBuf>) {
+        self.exportpath = path;
+    }
+
+    fn hasexportpath(&self) -> bool {
+        self.exportpath.is_some()
+    }
+
+    fn export_path(&self) -> PathBuf {
+        Simulation::export_path(self)
+    }
+
+    fn export(&self, path: &Path, bounded: bool) -> Result<()> {
+        Simulation::export(self, path, bounded)
+    }
+
+    fn hassnapshotpath(&self) -> bool {
+        self.snapshotpath.is_some()
+    }
+
+    fn snapshot_path(&self) -> PathBuf {
+        Simulation::snapshot_path(self)
+    }
+
+    fn save(&self, path: &Path) -> Result<()> {
+        Simulation::save(self, path)
+    }
+
+    fn step(&mut self) -> bool {
+        Simulation::step(self)
+    }
+
+    #[cfg(feature = "graphics")]
+    fn run(&mut self, ctx: &mut ggez::Context, events: &mut ggez::event::EventsLoop) -> ggez::GameResult {
+        ggez::event::run(ctx, events, self)
+    }
+}
@@ -0,0 +1,197 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::{Path, PathBuf};
+
+use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
+
+use crate::{Error, Result};
+use crate::simulation::{Automaton, SimGrid};
+use crate::simulation::Simulation;
+
+/// A struct that represents a snapshot of the full state of an automaton.
+/// Snapshots are stored on disk as JSON. The grids reject unknown fields, so the
+/// state of another grid (ex: from a snapshot without a grid name) is not restored.
+#[derive(Serialize, Deserialize)]
+pub struct Snapshot<T> {
+    /// Represents the name of the type of automaton in the snapshot (that of a null automaton).
+    pub automaton: String,
+    /// Represents the name of the type of grid of the automaton in the snapshot.
+    #[serde(default)]
+    pub grid: String,
+    /// Represents the state of the automaton.
+    pub state: T,
+}
+
+/// Implementation of snapshot methods for Simulation.
+impl<T: Automaton> Simulation<T> {
+    /// A constructor function that resumes a simulation from the snapshot at the
+    /// given path with the given cell size (pixels) and refresh rate (seconds).
+    /// The snapshot must have been saved from the same type of automaton and grid.
+    pub fn resume(path: &Path, cellsize: f32, fps: u32) -> Result<Self> where T: DeserializeOwned {
+        // Read the snapshot from the file
+        let reader = BufReader::new(File::open(path)?);
+        let snapshot: Snapshot<serde_json::Value> = serde_json::from_reader(reader)?;
+
+        // Check that the snapshot is of the same type of automaton and grid
+        // (a null automaton provides the names). Grids of different types
+        // may serialize to the same shape, so the state alone cannot tell them apart.
+        let null = T::new("default", 0);
+        let (name, grid) = (null.name(), null.grid().name());
+        if snapshot.automaton != name || snapshot.grid != grid {
+            return Err(Error::SnapshotMismatch {
+                expected: format!("{} | {}", name, grid),
+                found: format!("{} | {}", snapshot.automaton, snapshot.grid),
+            });
+        }
+
+        // Restore the automaton from the snapshot state
+        let automaton: T = serde_json::from_value(snapshot.state)?;
+
+        // Create the simulation with the restored automaton
+        let gridsize = (automaton.grid().getwidth(), automaton.grid().getheight());
+        let mut simulation = Self::from_automaton(automaton, cellsize, fps)?;
+        // The restored automaton is already initialized and keeps the size of its grid
+        simulation.gridsize = Some(gridsize);
+        simulation.fitted = false;
+        simulation.initialized = true;
+
+        Ok(simulation)
+    }
+
+    /// A method that saves a snapshot of the automaton to the given path.
+    pub fn save(&self, path: &Path) -> Result<()> where T: Serialize {
+        // Create the snapshot of the automaton. The name of the automaton may depend on its
+        // state (ex: the rule of a Life-like automaton), so that of a null automaton is used
+        let snapshot = Snapshot {
+            automaton: T::new("default", 0).name(),
+            grid: self.automaton.grid().name(),
+            state: &self.automaton,
+        };
+
+        // Write the snapshot to the file
+        let writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer(writer, &snapshot)?;
+
+        Ok(())
+    }
+
+    /// A method that exports the live cells of the grid as a pattern file at the given path.
+    /// The format is chosen from the file extension. If bounded is set, the pattern
+    /// is cropped to the bounding box of the live cells.
+    pub fn export(&self, path: &Path, bounded: bool) -> Result<()> {
+        self.automaton.pattern(bounded)?.save(path)
+    }
+
+    /// A method that returns the path that patterns of the simulation are exported to.
+    /// Defaults to "pattern-{seed}-{generation}.rle" if no path has been set.
+    pub fn export_path(&self) -> PathBuf {
+        match &self.exportpath {
+            Some(path) => path.clone(),
+            None => PathBuf::from(format!("pattern-{}-{}.rle", self.automaton.seed(), self.automaton.generation())),
+        }
+    }
+
+    /// A method that returns the path that snapshots of the simulation are saved to.
+    /// Defaults to "snapshot-{seed}-{generation}.json" if no path has been set.
+    pub fn snapshot_path(&self) -> PathBuf {
+        match &self.snapshotpath {
+            Some(path) => path.clone(),
+            None => PathBuf::from(format!("snapshot-{}-{}.json", self.automaton.seed(), self.automaton.generation())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Debug;
+    use std::path::PathBuf;
+
+    use serde::Serialize;
+    use serde::de::DeserializeOwned;
+
+    use crate::Error;
+    use crate::simulation::{Automaton, Simulation};
+    use crate::commons::cells::BinaryCell;
+    use crate::commons::grids::{BitGrid, CellGrid, QuadTree, SparseGrid};
+    use crate::gameoflife::GameOfLife;
+    use crate::langtonsant::LangtonsAnt;
+
+    // A function that returns a path for a snapshot of the test with the given name
+    fn snapshot(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("automata-{}-{}.json", name, std::process::id()))
+    }
+
+    // A function that returns the state of the given automaton and its cells (in a sorted order)
+    fn state<T: Automaton>(automaton: &T) -> (String, String, Vec<String>) where <T::Grid as IntoIterator>::Item: Debug {
+        let mut cells: Vec<String> = automaton.grid().clone().into_iter().map(|cell| format!("{:?}", cell)).collect();
+        cells.sort_unstable();
+        (automaton.state(), automaton.fullname(), cells)
+    }
+
+    // A function that advances a simulation of the given automaton, saves it to a snapshot and
+    // checks that the resumed simulation has the same state and advances in the same way
+    fn round_trip<T>(automaton: T, name: &str)
+    where
+        T: Automaton + Serialize + DeserializeOwned,
+        <T::Grid as IntoIterator>::Item: Debug,
+    {
+        let path = snapshot(name);
+        let mut simulation = Simulation::from_automaton(automaton, 10.0, 0).unwrap();
+        simulation.initialize(30, 20).unwrap();
+        for _ in 0..12 {
+            simulation.advance();
+        }
+
+        // Save and resume the simulation
+        simulation.save(&path).unwrap();
+        let mut resumed = Simulation::<T>::resume(&path, 10.0, 0).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(resumed.initialized, "{}", name);
+        assert_eq!(resumed.gridsize, Some((30, 20)), "{}", name);
+        assert_eq!(state(&resumed.automaton), state(&simulation.automaton), "{}", name);
+
+        // Check that both simulations advance in the same way
+        for _ in 0..12 {
+            simulation.advance();
+            resumed.advance();
+        }
+        assert_eq!(state(&resumed.automaton), state(&simulation.automaton), "{}", name);
+    }
+
+    #[test]
+    fn save_and_resume() {
+        round_trip(GameOfLife::<CellGrid<BinaryCell>>::new("random-balanced", 5), "grid");
+        round_trip(GameOfLife::<BitGrid>::new("random-balanced", 5), "packed");
+        round_trip(GameOfLife::<SparseGrid>::new("random-balanced", 5), "sparse");
+        round_trip(GameOfLife::<QuadTree>::new("random-balanced", 5), "hashlife");
+        round_trip(LangtonsAnt::<CellGrid<BinaryCell>>::new("default", 5), "ant-grid");
+        round_trip(LangtonsAnt::<SparseGrid>::new("default", 5), "ant-sparse");
+
+        // A custom rule is kept in the snapshot
+        let mut automaton = GameOfLife::<BitGrid>::new("random-balanced", 5);
+        automaton.setrule("B36/S23").unwrap();
+        round_trip(automaton, "rule");
+    }
+
+    #[test]
+    fn resume_mismatch() {
+        // Save a snapshot of the game of life on a sparse grid
+        let path = snapshot("mismatch");
+        let mut simulation = Simulation::from_automaton(GameOfLife::<SparseGrid>::new("glider", 0), 10.0, 0).unwrap();
+        simulation.initialize(10, 10).unwrap();
+        simulation.save(&path).unwrap();
+
+        // Check that the snapshot is only resumed by the same automaton and grid
+        let grid = Simulation::<GameOfLife<CellGrid<BinaryCell>>>::resume(&path, 10.0, 0).err();
+        let quadtree = Simulation::<GameOfLife<QuadTree>>::resume(&path, 10.0, 0).err();
+        let ant = Simulation::<LangtonsAnt<SparseGrid>>::resume(&path, 10.0, 0).err();
+        let sparse = Simulation::<GameOfLife<SparseGrid>>::resume(&path, 10.0, 0).err();
+        std::fs::remove_file(&path).unwrap();
+
+        for error in [grid, quadtree, ant] {
+            assert!(matches!(error, Some(Error::SnapshotMismatch { .. })), "{:?}", error);
+        }
+        assert!(sparse.is_none());
+    }
+}
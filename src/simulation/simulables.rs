@@ -1,17 +1,24 @@
-use ggez::graphics;
-use crate::commons::grids::GridCell;
+use rand::Rng;
+
+use crate::{Error, Result};
+use crate::commons::grids::{Boundary, CellGrid, GridPoint, Pattern, Placement};
 use crate::commons::navigation::Orient;
 
 /// A trait for a simulation cell
-/// The cell must be cloneable
-pub trait SimCell: Clone + Copy {
-    /// A constructor method that generates a random 
-    /// new cell with a balanced ratio.
-    fn balanced() -> Self;
-
-    /// A constructor method that generates a random
-    /// new cell with a skewed ratio.
-    fn skewed(skew: &str, bias: i8) -> Self;
+/// The cell must be cloneable and comparable
+pub trait SimCell: Clone + Copy + PartialEq {
+    /// A constructor method that generates a random new
+    /// cell with a balanced ratio from the given generator.
+    fn balanced<R: Rng + ?Sized>(rng: &mut R) -> Self;
+
+    /// A constructor method that generates a random new
+    /// cell with a skewed ratio from the given generator.
+    /// Returns an error if the skew value is not supported by the cell.
+    fn skewed<R: Rng + ?Sized>(rng: &mut R, skew: &str, bias: i8) -> Result<Self>;
+
+    /// A method that returns the next state of the cell.
+    /// Cycles back to the first state after the last one.
+    fn cycle(&self) -> Self;
 }
 
 /// A trait for simulation grids.
@@ -19,49 +26,152 @@ pub trait SimCell: Clone + Copy {
 pub trait SimGrid: Clone + IntoIterator {
     /// Defines the type of cell used in the grid.
     type Cell: SimCell;
-    
+
     /// Defines the type of navigation used in the grid.
-    type Orientation: Orient; 
+    type Orientation: Orient;
 
     /// A constructor method that create a null grid.
-    fn new(cellsize: f32) -> Self;
+    fn new() -> Self;
 
-    /// A setter method that sets the grid vector.
-    fn setgrid(&mut self, other: Vec<Vec<Self::Cell>>);
+    /// A getter method that returns the name of the type of grid.
+    /// Ex: "Sparse Grid"
+    fn name(&self) -> String;
 
-    /// A setter method that sets the grid dimensions.
-    fn setdimensions(&mut self, dimensions: graphics::Rect);
+    /// A setter method that sets the cells of the grid from the given grid of cells.
+    fn setgrid(&mut self, other: CellGrid<Self::Cell>);
 
-    /// A getter method that returns the grid vector's height (no.of rows).
+    /// A setter method that sets the cell at the given (signed) position.
+    /// Returns false if the position is outside the grid.
+    fn setcell(&mut self, x: isize, y: isize, cell: Self::Cell) -> bool;
+
+    /// A getter method that returns the grid's height (no.of rows).
     fn getheight(&self) -> usize;
 
-    /// A getter method that returns the grid vector's width (no.of columns).
+    /// A getter method that returns the grid's width (no.of columns).
     fn getwidth(&self) -> usize;
 
-    /// A method that returns a random cell from the grid.
-    fn randomcell(&self) -> GridCell<Self::Cell>;
+    /// A getter method that returns the cell at the given (signed) position.
+    /// Returns None if the position is outside the grid.
+    fn getcell(&self, x: isize, y: isize) -> Option<Self::Cell>;
+
+    /// A getter method that returns the policy for positions beyond the edges of the grid.
+    fn boundary(&self) -> Boundary;
+
+    /// A setter method that sets the policy for positions beyond the edges of the grid.
+    fn setboundary(&mut self, boundary: Boundary);
+
+    /// A method that returns a random cell from the grid using the given generator.
+    /// Returns an error if the grid is null or empty.
+    fn randomcell<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<GridPoint<Self::Cell>>;
+
+    /// A method that returns the state in the given previous grid
+    /// of every cell that differs between it and the current grid.
+    fn changes(&self, previous: &Self) -> Vec<GridPoint<Self::Cell>>;
 }
 
 /// A trait for simulation automaton.
-/// The automaton must be drawable.
-pub trait Automaton: graphics::Drawable {
+/// The automaton is independent of any rendering backend.
+pub trait Automaton {
     /// Defines the type of grid used in the automaton.
     type Grid: SimGrid;
 
-    /// A constructor method that creates a null automaton  
-    /// and sets intialization parameters into the object.
-    fn new(initialstate: &str, cellsize: f32) -> Self;
+    /// Defines the type of checkpoint for the automaton. A checkpoint holds
+    /// all the state of the automaton that is not on its grid (such as the
+    /// generation, counters and agents) and is used to rewind the automaton.
+    type Checkpoint: Clone;
+
+    /// A constructor method that creates a null automaton and sets intialization
+    /// parameters into the object. The seed drives all randomness of the automaton.
+    fn new(initialstate: &str, seed: u64) -> Self;
 
-    /// A method that initializes the automaton with 
-    /// its initial state for the given dimensions.
-    fn initialize(&mut self, dimensions: graphics::Rect);
+    /// A method that initializes the automaton with its
+    /// initial state for the given grid size (in cells). Returns an error if the
+    /// initial state is not supported or the grid cannot be built.
+    fn initialize(&mut self, width: usize, height: usize) -> Result<()>;
 
     /// A method that advances the automaton to the next generation.
     fn advance(&mut self);
 
-    /// A method that returns the current state of the 
+    /// A method that advances the automaton by the given number of generations at once.
+    /// Defaults to advancing until the generation is reached. Halted automata keep counting
+    /// generations, so it only stops early if the automaton can no longer progress.
+    fn advance_by(&mut self, generations: u32) {
+        let target = self.generation().saturating_add(generations);
+        while self.generation() < target {
+            // Advance the automaton and stop if it halted without reaching a new generation
+            let generation = self.generation();
+            self.advance();
+            if self.generation() == generation && self.halted() {
+                break;
+            }
+        }
+    }
+
+    /// A setter method that sets the cell at the given position
+    /// of the grid and updates the automaton state to match.
+    /// Makes no change if the position is outside the grid.
+    fn setcell(&mut self, x: isize, y: isize, cell: <Self::Grid as SimGrid>::Cell);
+
+    /// A getter method that returns the grid of the automaton.
+    fn grid(&self) -> &Self::Grid;
+
+    /// A setter method that sets the policy for positions beyond the edges of the grid.
+    /// Returns an error if the grid has no edges.
+    fn setboundary(&mut self, boundary: Boundary) -> Result<()>;
+
+    /// A method that resizes the grid of an initialized automaton to the given dimensions
+    /// (in cells). The cells that remain within the grid keep their positions and the
+    /// counters of the automaton are updated to match. Returns an error if the
+    /// automaton does not support resizing.
+    fn resize(&mut self, width: usize, height: usize) -> Result<()> {
+        Err(Error::Unsupported { automaton: self.name(), option: format!("resizing (given {}x{})", width, height) })
+    }
+
+    /// A setter method that sets the rule of the automaton from its string notation.
+    /// Returns an error if the rule is invalid or the automaton does not support rules.
+    fn setrule(&mut self, rule: &str) -> Result<()> {
+        Err(Error::Unsupported { automaton: self.name(), option: format!("rules (given '{}')", rule) })
+    }
+
+    /// A method that places the given pattern onto the grid of an initialized automaton.
+    /// Returns an error if the automaton does not support patterns.
+    fn place(&mut self, _pattern: &Pattern, _placement: Placement) -> Result<()> {
+        Err(Error::Unsupported { automaton: self.name(), option: "patterns".to_string() })
+    }
+
+    /// A method that returns the live cells of the grid as a pattern. If bounded is set,
+    /// the pattern is cropped to the bounding box of the live cells.
+    /// Returns an error if the automaton does not support patterns.
+    fn pattern(&self, _bounded: bool) -> Result<Pattern> {
+        Err(Error::Unsupported { automaton: self.name(), option: "patterns".to_string() })
+    }
+
+    /// A method that returns a checkpoint of the current state of the automaton.
+    fn checkpoint(&self) -> Self::Checkpoint;
+
+    /// A method that restores the automaton to the given checkpoint.
+    /// The grid is left untouched and must be restored separately.
+    fn restore(&mut self, checkpoint: Self::Checkpoint);
+
+    /// A getter method that returns the current generation of the automaton.
+    fn generation(&self) -> u32;
+
+    /// A getter method that returns the seed of the automaton.
+    fn seed(&self) -> u64;
+
+    /// A getter method that returns the number of alive cells on the grid.
+    fn alive(&self) -> u32;
+
+    /// A getter method that returns the number of dead cells on the grid.
+    fn dead(&self) -> u32;
+
+    /// A method that returns whether the automaton has halted,
+    /// i.e. advancing it further will not change its grid.
+    fn halted(&self) -> bool;
+
+    /// A method that returns the current state of the
     /// automaton as a formatted string.
-    fn state(&self) -> String;  
+    fn state(&self) -> String;
 
     /// A method that returns the name of the automaton as a string.
     /// Ex: "Conway's Game of Life"
@@ -70,5 +180,5 @@ pub trait Automaton: graphics::Drawable {
     /// A method that returns the name of the automaton along
     /// with its initial state and grid type as formatted string.
     /// Ex: "Conway's Game of Life | Grid | Random [1:1]"
-    fn fullname(&self) -> String;    
+    fn fullname(&self) -> String;
 }
@@ -1,25 +1,245 @@
-use ggez::GameResult;
-use crate::simulation::Automaton;
+use std::path::PathBuf;
+
+use crate::{Error, Result};
+use crate::commons::grids::{Pattern, Placement};
+use crate::simulation::{Automaton, Camera, History, SimCell, SimGrid};
 
 /// A struct that represents the simulator for a generic automaton.
 pub struct Simulation<T> where T: Automaton {
     /// Represents the automaton that is being simulated.
     pub automaton: T,
+    /// Represents the size of an individual cell (pixels).
+    pub cellsize: f32,
+    /// Represents the size of the grid (in cells). The grid
+    /// fills the window when rendered if it is not set.
+    pub gridsize: Option<(usize, usize)>,
+    /// Represents whether the grid is resized to fit the window when the window is resized.
+    /// Set if the size of the grid is derived from the window.
+    pub fitted: bool,
+    /// Represents the camera over the grid when rendered.
+    pub camera: Camera,
     /// Represents the refresh rate of the simulation.
     pub fps: u32,
+    /// Represents the number of generations the automaton is advanced by at a time.
+    pub stride: u32,
     /// Represents whether the simulation has been initialized.
     pub initialized: bool,
+    /// Represents whether the simulation is paused.
+    pub paused: bool,
+    /// Represents the cell state being painted onto the grid, if any.
+    pub brush: Option<<T::Grid as SimGrid>::Cell>,
+    /// Represents the path that snapshots are saved to, if set.
+    pub snapshotpath: Option<PathBuf>,
+    /// Represents the path that patterns are exported to, if set.
+    pub exportpath: Option<PathBuf>,
+    /// Represents the pattern placed onto the grid when initialized, if any.
+    pub pattern: Option<(Pattern, Placement)>,
+    /// Represents the history of previous generations (disabled by default).
+    pub history: History<T>,
+    /// Represents the grid before the latest advance (reused to record the history).
+    previous: T::Grid,
 }
 
 /// Implementation of constructor for Simulation.
 impl<T: Automaton> Simulation<T> {
-    /// A constructor function that creates a new simulation automaton with
-    /// the given intial state, cell size (pixels) and refresh rate (seconds).
-    pub fn new(initialstate: &str, cellsize: f32, fps: u32) -> GameResult<Self> {
-        // Create the simulation, wrap it in a GameResult and return it.
-        Ok(Self{
-            fps, initialized: false,
-            automaton: T::new(initialstate, cellsize), 
+    /// A constructor function that creates a new simulation automaton with the
+    /// given intial state, seed, cell size (pixels) and refresh rate (seconds).
+    /// Returns an error if the cell size is not a positive number.
+    pub fn new(initialstate: &str, seed: u64, cellsize: f32, fps: u32) -> Result<Self> {
+        Self::from_automaton(T::new(initialstate, seed), cellsize, fps)
+    }
+
+    /// A constructor function that creates a new simulation for the given
+    /// automaton with the given cell size (pixels) and refresh rate (seconds).
+    /// Returns an error if the cell size is not a positive number.
+    pub fn from_automaton(automaton: T, cellsize: f32, fps: u32) -> Result<Self> {
+        // Check that the cell size is a positive number
+        if !cellsize.is_finite() || cellsize <= 0.0 {
+            return Err(Error::InvalidCellSize(cellsize));
+        }
+
+        Ok(Self {
+            automaton, cellsize, fps, stride: 1,
+            initialized: false, paused: false, brush: None,
+            snapshotpath: None, exportpath: None, pattern: None,
+            history: History::new(0), previous: T::Grid::new(),
+            gridsize: None, fitted: true, camera: Camera::default(),
         })
     }
-}
\ No newline at end of file
+
+    /// A method that sets the size of the grid (in cells) independent of the window size.
+    /// The grid keeps its size when the window is resized.
+    pub fn with_gridsize(mut self, width: usize, height: usize) -> Self {
+        self.gridsize = Some((width, height));
+        self.fitted = false;
+        self
+    }
+
+    /// A method that initializes the automaton for the given grid size (in cells).
+    /// Used by headless simulations that are not sized from a window. The pattern
+    /// of the simulation is placed onto the grid once it has been initialized.
+    /// Returns an error if the automaton could not be initialized.
+    pub fn initialize(&mut self, width: usize, height: usize) -> Result<()> {
+        // Initialize the automaton with the grid dimensions
+        self.automaton.initialize(width, height)?;
+        // Place the pattern onto the grid
+        if let Some((pattern, placement)) = &self.pattern {
+            self.automaton.place(pattern, *placement)?;
+        }
+        // Set the initialized flag to true
+        self.initialized = true;
+
+        Ok(())
+    }
+
+    /// A method that resizes the grid to the given dimensions (in cells). The cells that remain
+    /// within the grid keep their positions. The grid of an uninitialized simulation is created
+    /// at the new size once initialized. The history is cleared since it cannot rewind past the resize.
+    /// Returns an error if the dimensions have no cells or the automaton does not support resizing.
+    pub fn resize(&mut self, width: usize, height: usize) -> Result<()> {
+        // Check that the grid dimensions hold at least one cell
+        if width == 0 || height == 0 {
+            return Err(Error::InvalidDimensions { width, height });
+        }
+
+        // Resize the grid of the automaton if it has been initialized
+        if self.initialized {
+            self.automaton.resize(width, height)?;
+            self.history.clear();
+        }
+        // Set the grid size of the simulation
+        self.gridsize = Some((width, height));
+
+        Ok(())
+    }
+
+    /// A method that advances the automaton and records the change in the history.
+    pub fn advance(&mut self) {
+        // Advance the automaton directly if the history is disabled
+        if self.history.capacity() == 0 {
+            self.forward();
+            return;
+        }
+
+        // Capture the state of the automaton before it advances
+        // (copied into the previous grid to reuse its allocation)
+        self.previous.clone_from(self.automaton.grid());
+        let checkpoint = self.automaton.checkpoint();
+        let generation = self.automaton.generation();
+
+        // Advance the automaton and record the change
+        self.forward();
+        self.history.record(&self.previous, checkpoint, &self.automaton, generation);
+    }
+
+    /// A method that sets the number of generations the automaton is advanced by at a time.
+    /// A stride of 0 is treated as 1.
+    pub fn setstride(&mut self, stride: u32) {
+        self.stride = stride.max(1);
+    }
+
+    // A method that advances the automaton by its stride. A stride of 1
+    // advances the automaton directly (which may take less than a generation).
+    fn forward(&mut self) {
+        match self.stride {
+            1 => self.automaton.advance(),
+            stride => self.automaton.advance_by(stride),
+        }
+    }
+
+    /// A method that rewinds the automaton to its previous recorded state.
+    /// Returns false if there is no history to rewind to.
+    pub fn rewind(&mut self) -> bool {
+        self.history.rewind(&mut self.automaton)
+    }
+
+    /// A method that advances the automaton until it reaches its next generation.
+    /// Returns false if the automaton halted before reaching a new generation.
+    pub fn step(&mut self) -> bool {
+        // Get the current generation of the automaton
+        let generation = self.automaton.generation();
+
+        // Advance the automaton until the generation changes. Some automata
+        // (such as langton's ant) take more than one advance per generation.
+        loop {
+            self.advance();
+
+            // Check if the automaton has reached a new generation
+            if self.automaton.generation() != generation {
+                return true;
+            }
+            // Check if the automaton can no longer progress
+            if self.automaton.halted() {
+                return false;
+            }
+        }
+    }
+
+    /// A method that starts painting the grid from the cell at the given position.
+    /// The brush paints the cycled state of that cell, which is applied to it immediately.
+    pub fn start_brush(&mut self, x: isize, y: isize) {
+        // Get the cell at the position (ignore positions outside the grid)
+        if let Some(cell) = self.automaton.grid().getcell(x, y) {
+            // Set the brush to the next state of the cell and paint it.
+            // The history is cleared since it cannot rewind past the edit.
+            self.brush = Some(cell.cycle());
+            self.history.clear();
+            self.paint(x, y);
+        }
+    }
+
+    /// A method that paints the cell at the given position with the brush.
+    /// Makes no change if there is no brush or the position is outside the grid.
+    pub fn paint(&mut self, x: isize, y: isize) {
+        if let Some(brush) = self.brush {
+            self.automaton.setcell(x, y, brush);
+        }
+    }
+
+    /// A method that stops painting the grid.
+    pub fn stop_brush(&mut self) {
+        self.brush = None;
+    }
+
+    /// A method that pauses the simulation if it is running and resumes it if it is paused.
+    pub fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+    }
+
+    /// A method that doubles the refresh rate of the simulation.
+    /// Makes no change if the refresh rate is already unlimited (0).
+    pub fn speedup(&mut self) {
+        if self.fps != 0 {
+            self.fps = self.fps.saturating_mul(2);
+        }
+    }
+
+    /// A method that halves the refresh rate of the simulation down to a minimum of 1.
+    /// An unlimited refresh rate (0) is slowed down to 60 FPS.
+    pub fn slowdown(&mut self) {
+        self.fps = match self.fps {
+            0 => 60,
+            fps => (fps / 2).max(1),
+        };
+    }
+
+    /// A method that returns the speed of the simulation as a string.
+    /// Format: "Paused" or "{} FPS" or "Max FPS"
+    pub fn speed(&self) -> String {
+        match (self.paused, self.fps) {
+            (true, _) => "Paused".to_string(),
+            (false, 0) => "Max FPS".to_string(),
+            (false, fps) => format!("{} FPS", fps),
+        }
+    }
+
+    /// A method that advances the automaton by the given number of generations.
+    /// Stops early if the automaton halts without reaching a new generation.
+    pub fn run(&mut self, generations: u32) {
+        for _ in 0..generations {
+            if !self.step() {
+                break;
+            }
+        }
+    }
+}